@@ -15,6 +15,8 @@ from flood.ops import get_remote_installation
 from flood.ops import get_dependency_versions
 from flood.runners import load_single_run_test_payload
 from flood.runners import load_single_run_results_payload
+from flood.runners import load_single_run_raw_output
+from flood.runners import replay
 from flood.runners import run
 from flood.tests.equality_tests import run_equality_test
 from flood.tests.load_tests import run_load_test
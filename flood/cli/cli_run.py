@@ -22,6 +22,7 @@ def run_cli(raw_command: str | None = None) -> None:
         ('help',): 'toolcli.command_utils.standard_subcommands.help_command',
         ('ls',): 'flood.cli.ls_command',
         ('print',): 'flood.cli.print_command',
+        ('replay',): 'flood.cli.replay_command',
         ('report',): 'flood.cli.report_command',
         ('samples', 'collect'): 'flood.cli.samples_collect_command',
         ('samples', 'download'): 'flood.cli.samples_download_command',
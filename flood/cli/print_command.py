@@ -41,18 +41,37 @@ def print_single(
     output_dir: str, metrics: typing.Sequence[str]
 ) -> None:
     test_payload = flood.load_single_run_test_payload(output_dir)
-    test = flood.generate_test(**test_payload['test_parameters'])
+    test_parameters = test_payload['test_parameters']
     results_payload = flood.load_single_run_results_payload(output_dir)
     results = results_payload['results']
 
     # print test summary
-    flood.runners.single_runner.single_runner_summary._print_single_run_preamble_copy(
-        test_name=test_payload['name'],
-        rates=[subtest['rate'] for subtest in test['attacks']],
-        durations=[subtest['duration'] for subtest in test['attacks']],
-        vegeta_args=[subtest['vegeta_args'] for subtest in test['attacks']],
-        output_dir=output_dir,
-    )
+    summary = flood.runners.single_runner.single_runner_summary
+    if test_parameters.get('mode') == 'search':
+        # search tests are generated adaptively, so cannot be regenerated
+        summary._print_single_run_preamble_copy(
+            test_name=test_payload['name'],
+            rates=test_parameters['rates'],  # type: ignore
+            durations=test_parameters['durations'],  # type: ignore
+            vegeta_args=test_parameters['vegeta_args'],
+            output_dir=output_dir,
+            mode='search',
+        )
+    else:
+        test = flood.generate_test(**test_parameters)
+        test_data = flood.user_io.parse_test_data(test)
+        if test_parameters.get('mode') == 'concurrency':
+            rates = test_data['concurrencies']
+        else:
+            rates = test_data['rates']
+        summary._print_single_run_preamble_copy(
+            test_name=test_payload['name'],
+            rates=rates,  # type: ignore
+            durations=test_data['durations'],
+            vegeta_args=test_data['vegeta_args'],
+            output_dir=output_dir,
+            mode=test_parameters.get('mode'),
+        )
 
     # print node data
     print()
@@ -72,6 +91,10 @@ def print_single(
         metrics=metrics,
         indent=4,
     )
+    throughput_search = results_payload.get('throughput_search')
+    if throughput_search is not None:
+        print()
+        summary._print_throughput_search_summary(throughput_search, indent=4)
 
 
 def print_multiple(output_dirs: typing.Sequence[str]) -> None:
@@ -97,7 +120,10 @@ def print_multiple(output_dirs: typing.Sequence[str]) -> None:
                 row.append(max(throughputs))
             else:
                 row.append(None)
-        row.append(max(results['target_rate']))
+        target_loads = results.get('target_concurrency')
+        if target_loads is None or None in target_loads:
+            target_loads = results['target_rate']
+        row.append(max(target_loads))
         rows.append(row)
     labels = ['test'] + conditions + ['max tested']
 
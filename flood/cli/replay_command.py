@@ -0,0 +1,125 @@
+from __future__ import annotations
+
+import typing
+
+import toolcli
+
+import flood
+
+help_message = """Replay a recorded log of RPC traffic against nodes
+
+[bold][title]Traffic Log Format[/bold][/title]
+- JSONL file, one request per line
+- Each line has [metavar]method[/metavar], [metavar]params[/metavar], and [metavar]timestamp[/metavar]
+- [metavar]timestamp[/metavar] can be unix seconds or an ISO 8601 string
+- Requests are sent with their original inter-arrival times"""  # noqa: E501
+
+
+def get_command_spec() -> toolcli.CommandSpec:
+    return {
+        'f': replay_command,
+        'help': help_message,
+        'args': [
+            {
+                'name': 'path',
+                'help': 'path of JSONL traffic log',
+            },
+            {
+                'name': 'nodes',
+                'nargs': '+',
+                'help': 'nodes to test (see [metavar]flood --help[/metavar] for syntax)',  # noqa: E501
+            },
+            {
+                'name': ['--speed'],
+                'type': float,
+                'help': 'speed multiplier applied to original timing\n(default = [metavar]1[/metavar], [metavar]2[/metavar] replays twice as fast)',  # noqa: E501
+            },
+            {
+                'name': ['-s', '--seed'],
+                'dest': 'random_seed',
+                'type': int,
+                'help': 'random seed to use, (default = current timestamp)',
+            },
+            {
+                'name': ['-q', '--quiet'],
+                'help': 'do not print output to [metavar]STDOUT[/metavar]',
+                'action': 'store_true',
+            },
+            {
+                'name': ['-o', '--output'],
+                'dest': 'output_dir',
+                'help': 'directory to save results, (default = new tmp dir)',
+            },
+            {
+                'name': ['--dry'],
+                'help': 'only construct test, do not run it',
+                'action': 'store_true',
+            },
+            {
+                'name': ['--metrics'],
+                'nargs': '+',
+                'help': 'space-separated list of performance metrics to show\n(default = [metavar]success throughput p90[/metavar])',  # noqa: E501
+            },
+            {
+                'name': ['--no-figures'],
+                'help': 'skip generating summary figures in output_dir',
+                'dest': 'figures',
+                'action': 'store_false',
+            },
+            {
+                'name': ['--save-raw-output'],
+                'help': 'save the contents of every RPC response',
+                'action': 'store_true',
+            },
+            {
+                'name': ['--deep-check'],
+                'help': 'validate the contents of every RPC response',
+                'action': 'store_true',
+            },
+            {
+                'name': ['--engine'],
+                'choices': ['native'],
+                'help': 'load engine used to send requests\n(replay requires [metavar]native[/metavar])',  # noqa: E501
+            },
+        ],
+        'examples': [
+            'traffic.jsonl localhost:8545',
+            'traffic.jsonl reth=localhost:8545 erigon=localhost:8546 --speed 2',  # noqa: E501
+        ],
+    }
+
+
+def replay_command(
+    path: str,
+    nodes: typing.Sequence[str],
+    speed: float | None,
+    random_seed: int | None,
+    quiet: bool,
+    output_dir: str | None,
+    dry: bool,
+    metrics: typing.Sequence[str] | None,
+    figures: bool,
+    save_raw_output: bool,
+    deep_check: bool,
+    engine: flood.LoadEngine | None,
+) -> None:
+    include_deep_output: typing.List[flood.DeepOutput] = []
+    if deep_check:
+        include_deep_output.append('metrics')
+    if save_raw_output:
+        include_deep_output.append('raw')
+
+    flood.replay(
+        path,
+        nodes=nodes,
+        speed=speed,
+        random_seed=random_seed,
+        verbose=not quiet,
+        dry=dry,
+        output_dir=output_dir,
+        figures=figures,
+        metrics=metrics,
+        include_deep_output=include_deep_output,
+        deep_check=deep_check,
+        engine=engine,
+    )
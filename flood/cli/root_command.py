@@ -64,9 +64,8 @@ def get_command_spec() -> toolcli.CommandSpec:
             },
             {
                 'name': ['-m', '--mode'],
-                'choices': ['stress', 'spike', 'soak'],
-                'hidden': True,
-                'help': 'load test type: stress, spike, or soak',
+                'choices': ['stress', 'spike', 'soak', 'concurrency', 'search'],  # noqa: E501
+                'help': 'load test type: stress, spike, soak, concurrency, or search\n([metavar]concurrency[/metavar] uses rates as numbers of concurrent workers)\n([metavar]search[/metavar] finds max rate that meets SLO, rates = start [max])',  # noqa: E501
             },
             {
                 'name': ['-r', '--rates'],
@@ -78,6 +77,50 @@ def get_command_spec() -> toolcli.CommandSpec:
                 'type': int,
                 'help': 'number of seconds to test each rate (default = [metavar]30[/metavar])',  # noqa: E501
             },
+            {
+                'name': ['-b', '--batch-size'],
+                'type': int,
+                'help': 'send calls as JSON-RPC batches of this size\n(rates are then in units of batches per second)',  # noqa: E501
+            },
+            {
+                'name': ['--think-time'],
+                'type': float,
+                'help': 'seconds each worker waits between requests\n(only used with [metavar]--mode concurrency[/metavar])',  # noqa: E501
+            },
+            {
+                'name': ['-w', '--weights'],
+                'nargs': '+',
+                'help': 'method weights for [metavar]mixed_workload[/metavar] test\ne.g. [metavar]eth_call=40 eth_getLogs=30 eth_getBalance=30[/metavar]',  # noqa: E501
+            },
+            {
+                'name': ['--blocks'],
+                'help': 'select blocks relative to chain head of each node, either\na block tag ([metavar]latest[/metavar], [metavar]safe[/metavar], [metavar]finalized[/metavar]) or a number of recent blocks',  # noqa: E501
+            },
+            {
+                'name': ['--slo-p99'],
+                'type': float,
+                'help': 'max p99 latency in seconds for [metavar]--mode search[/metavar]\n(default = [metavar]0.5[/metavar])',  # noqa: E501
+            },
+            {
+                'name': ['--slo-success'],
+                'type': float,
+                'help': 'min success rate for [metavar]--mode search[/metavar]\n(default = [metavar]0.999[/metavar])',  # noqa: E501
+            },
+            {
+                'name': ['--stop-success'],
+                'type': float,
+                'help': 'skip remaining rates once success rate falls below this',  # noqa: E501
+            },
+            {
+                'name': ['--stop-p99'],
+                'type': float,
+                'help': 'skip remaining rates once p99 latency exceeds this (s)',  # noqa: E501
+            },
+            {
+                'name': ['--stop-timeouts'],
+                'type': int,
+                'help': 'skip remaining rates once this many consecutive\nrequests time out',  # noqa: E501
+            },
             {
                 'name': ['-o', '--output'],
                 'dest': 'output_dir',
@@ -115,6 +158,32 @@ def get_command_spec() -> toolcli.CommandSpec:
                 'hidden': True,
                 'action': 'store_true',
             },
+            {
+                'name': ['--engine'],
+                'choices': ['vegeta', 'native'],
+                'help': 'load engine used to send requests\n(default = [metavar]vegeta[/metavar], [metavar]native[/metavar] does not require vegeta)',  # noqa: E501
+            },
+            {
+                'name': ['--parallel'],
+                'help': 'attack all local nodes at the same time, each from a\nseparate loader process',  # noqa: E501
+                'action': 'store_true',
+            },
+            {
+                'name': ['--node-metrics'],
+                'nargs': '+',
+                'help': 'prometheus urls to scrape during each attack\ne.g. [metavar]reth=localhost:9001 geth=localhost:6060/debug/metrics/prometheus[/metavar]',  # noqa: E501
+            },
+            {
+                'name': ['--loaders'],
+                'nargs': '+',
+                'help': 'remote hosts that each send a share of every attack\ne.g. [metavar]user@loader1 user@loader2[/metavar]',  # noqa: E501
+            },
+            {
+                'name': ['--start-at'],
+                'type': float,
+                'help': 'unix time at which to start first attack',
+                'hidden': True,
+            },
             {
                 'name': ['--vegeta-args'],
                 'help': 'extra args for vegeta, e.g. [metavar]"-timeout 5s -cpus 1"[/metavar]\nfor single args, use [metavar]--vegeta-args="..."[/metavar] (no space)',  # noqa: E501
@@ -141,6 +210,15 @@ def root_command(
     mode: flood.LoadTestMode | None,
     rates: typing.Sequence[int] | typing.Sequence[str] | None,
     duration: int | None,
+    batch_size: int | None,
+    think_time: float | None,
+    slo_p99: float | None,
+    slo_success: float | None,
+    stop_success: float | None,
+    stop_p99: float | None,
+    stop_timeouts: int | None,
+    weights: typing.Sequence[str] | None,
+    blocks: str | None,
     random_seed: int | None,
     dry: bool,
     quiet: bool,
@@ -149,6 +227,11 @@ def root_command(
     save_raw_output: bool,
     deep_check: bool,
     remote_update: bool,
+    engine: flood.LoadEngine | None,
+    parallel: bool,
+    node_metrics: typing.Sequence[str] | None,
+    loaders: typing.Sequence[str] | None,
+    start_at: float | None,
     vegeta_args: str,
     version: bool,
 ) -> None:
@@ -168,10 +251,34 @@ def root_command(
             raise Exception('rates not used in equality test')
         if duration is not None:
             raise Exception('duration not used in equality test')
+        if batch_size is not None:
+            raise Exception('batch_size not used in equality test')
+        if think_time is not None:
+            raise Exception('think_time not used in equality test')
+        if slo_p99 is not None or slo_success is not None:
+            raise Exception('slo not used in equality test')
+        if (
+            stop_success is not None
+            or stop_p99 is not None
+            or stop_timeouts is not None
+        ):
+            raise Exception('stop conditions not used in equality test')
+        if weights is not None:
+            raise Exception('weights not used in equality test')
+        if blocks is not None:
+            raise Exception('blocks not used in equality test')
         if dry:
             raise Exception('dry not used in equality test')
         if not figures:
             raise Exception('figures not used in equality test')
+        if engine is not None:
+            raise Exception('engine not used in equality test')
+        if parallel:
+            raise Exception('parallel not used in equality test')
+        if loaders is not None or start_at is not None:
+            raise Exception('loaders not used in equality test')
+        if node_metrics is not None:
+            raise Exception('node_metrics not used in equality test')
         flood.run_equality_test(
             test_name=test,
             nodes=nodes,
@@ -189,6 +296,45 @@ def root_command(
 
         if rates is not None:
             rates = [int(rate) for rate in rates]
+
+        slo: flood.ThroughputSLO | None = None
+        if slo_p99 is not None or slo_success is not None:
+            if mode != 'search':
+                raise Exception('slo only used with search mode')
+            default_slo = flood.tests.load_tests.throughput_search.default_slo
+            slo = {
+                'max_p99': default_slo['max_p99'],
+                'min_success': default_slo['min_success'],
+            }
+            if slo_p99 is not None:
+                slo['max_p99'] = slo_p99
+            if slo_success is not None:
+                slo['min_success'] = slo_success
+
+        stop_conditions: flood.StopConditions | None = None
+        if (
+            stop_success is not None
+            or stop_p99 is not None
+            or stop_timeouts is not None
+        ):
+            stop_conditions = {
+                'min_success': stop_success,
+                'max_p99': stop_p99,
+                'max_consecutive_timeouts': stop_timeouts,
+            }
+
+        node_metrics_urls: typing.Mapping[str, str] | None = None
+        if node_metrics is not None:
+            node_metrics_urls = _parse_node_metrics(node_metrics)
+
+        parsed_weights: typing.Mapping[str, float] | None = None
+        if weights is not None:
+            parsed_weights = _parse_weights(weights)
+
+        parsed_blocks: flood.BlockSelection | None = None
+        if blocks is not None:
+            parsed_blocks = flood.generators.parse_block_selection(blocks)
+
         flood.run(
             test_name=test,
             mode=mode,
@@ -198,11 +344,43 @@ def root_command(
             verbose=verbose,
             rates=rates,
             duration=duration,
+            batch_size=batch_size,
+            think_time=think_time,
+            slo=slo,
+            weights=parsed_weights,
+            blocks=parsed_blocks,
             dry=dry,
             output_dir=output_dir,
             figures=figures,
             include_deep_output=include_deep_output,
             deep_check=deep_check,
             vegeta_args=vegeta_args,
+            engine=engine,
+            parallel=parallel,
+            stop_conditions=stop_conditions,
+            loaders=loaders,
+            start_time=start_at,
+            node_metrics_urls=node_metrics_urls,
         )
 
+
+def _parse_weights(weights: typing.Sequence[str]) -> typing.Mapping[str, float]:
+    parsed = {}
+    for item in weights:
+        if '=' not in item:
+            raise Exception('weights should be formatted as METHOD=WEIGHT')
+        method, weight = item.split('=', 1)
+        parsed[method] = float(weight)
+    return parsed
+
+
+def _parse_node_metrics(
+    node_metrics: typing.Sequence[str],
+) -> typing.Mapping[str, str]:
+    parsed = {}
+    for item in node_metrics:
+        if '=' not in item:
+            raise Exception('node metrics should be formatted as NODE=URL')
+        name, url = item.split('=', 1)
+        parsed[name] = url
+    return parsed
@@ -27,7 +27,7 @@ def get_command_spec() -> toolcli.CommandSpec:
             },
             {
                 'name': ('-d', '--datatypes'),
-                'help': 'datatypes to sample, default all',
+                'help': 'datatypes to sample, default all except blocks',
             },
             {
                 'name': ('-m', '--missing'),
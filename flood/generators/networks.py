@@ -0,0 +1,128 @@
+"""default parameters of each supported network
+
+networks are detected from the eth_chainId of each node
+
+block ranges are historical ranges that calls are sampled from:
+- blocks: blocks that are queried directly, e.g. eth_getBlockByNumber
+- state: blocks at which state and logs are queried
+- fees: blocks with EIP-1559 fee data, for eth_feeHistory
+
+ranges of rollups start after their current node software went live, since
+older blocks are often only served by legacy nodes
+"""
+from __future__ import annotations
+
+import typing
+
+from flood import spec
+
+
+network_defaults: typing.Mapping[str, spec.NetworkDefaults] = {
+    'ethereum': {
+        'chain_id': 1,
+        'block_ranges': {
+            'blocks': (0, 16_000_000),
+            'state': (10_000_000, 16_000_000),
+            'fees': (13_000_000, 17_000_000),
+        },
+        'contracts': {
+            'USDC': '0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48',
+            'DAI': '0x6b175474e89094c44da98b954eedeac495271d0f',
+            'LUSD': '0x5f98805a4e8be255a32880fdec7f6728c6568ba0',
+        },
+    },
+    'optimism': {
+        'chain_id': 10,
+        'block_ranges': {
+            'blocks': (105_235_063, 125_000_000),
+            'state': (113_000_000, 125_000_000),
+            'fees': (105_235_063, 125_000_000),
+        },
+        'contracts': {
+            'USDC': '0x0b2c639c533813f4aa9d7837caf62653d097ff85',
+            'DAI': '0xda10009cbd5d07dd0cecc66161fc93d7c9000da1',
+        },
+    },
+    'base': {
+        'chain_id': 8453,
+        'block_ranges': {
+            'blocks': (0, 10_000_000),
+            'state': (3_000_000, 10_000_000),
+            'fees': (0, 10_000_000),
+        },
+        'contracts': {
+            'USDC': '0x833589fcd6edb6e08f4c7c32d4f71b54bda02913',
+            'DAI': '0x50c5725949a6f0c72e6c4a641f24049a917db0cb',
+        },
+    },
+    'arbitrum': {
+        'chain_id': 42161,
+        'block_ranges': {
+            'blocks': (22_207_817, 200_000_000),
+            'state': (110_000_000, 200_000_000),
+            'fees': (22_207_817, 200_000_000),
+        },
+        'contracts': {
+            'USDC': '0xaf88d065e77c8cc2239327c5edb3a432268e5831',
+            'DAI': '0xda10009cbd5d07dd0cecc66161fc93d7c9000da1',
+        },
+    },
+    'sepolia': {
+        'chain_id': 11155111,
+        'block_ranges': {
+            'blocks': (0, 6_000_000),
+            'state': (5_000_000, 6_000_000),
+            'fees': (0, 6_000_000),
+        },
+        'contracts': {
+            'USDC': '0x1c7d4b196cb0c7b01d743fbc6116a902379c7238',
+        },
+    },
+}
+
+
+def get_network_defaults(network: str | None) -> spec.NetworkDefaults:
+    """get default parameters of network, networks of None are ethereum"""
+    if network is None:
+        network = 'ethereum'
+    if network not in network_defaults:
+        raise Exception(
+            'unsupported network: '
+            + str(network)
+            + ', supported networks are '
+            + ', '.join(network_defaults.keys())
+        )
+    return network_defaults[network]
+
+
+def get_network_from_chain_id(chain_id: int) -> str | None:
+    """get name of network with chain id, or None if not supported"""
+    for network, defaults in network_defaults.items():
+        if defaults['chain_id'] == chain_id:
+            return network
+    return None
+
+
+def get_unsupported_chain_id_message(chain_id: int) -> str:
+    """describe chain id that does not match any supported network"""
+    return (
+        'unsupported chain id: '
+        + str(chain_id)
+        + ', supported networks are '
+        + ', '.join(network_defaults.keys())
+    )
+
+
+def get_network_block_range(
+    network: str | None, kind: str
+) -> tuple[int, int]:
+    """get default range of blocks of network, see module docstring"""
+    block_ranges = get_network_defaults(network)['block_ranges']
+    if kind not in block_ranges:
+        raise Exception('unknown kind of block range: ' + str(kind))
+    return block_ranges[kind]
+
+
+def get_network_contracts(network: str | None) -> typing.Mapping[str, str]:
+    """get default token contracts of network, by symbol"""
+    return get_network_defaults(network)['contracts']
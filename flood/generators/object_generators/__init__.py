@@ -1,6 +1,9 @@
 from .address_generators import *
 from .block_generators import *
 from .call_generators import *
+from .chain_head import *
+from .lazy_calls import *
+from .replay_generators import *
 from .slot_generators import *
 from .timing_generators import *
 from .transaction_generators import *
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import typing
 
+from flood import generators
 from flood import spec
 from .. import rng_utils
 
@@ -38,7 +39,14 @@ def generate_block_hashes(
     network: str | None = None,
     random_seed: spec.RandomSeed | None = None,
 ) -> typing.Sequence[str]:
-    raise NotImplementedError()
+    if network is None:
+        raise Exception('must specify network to sample block hashes')
+    return generators.load_samples(
+        network=network,
+        datatype='blocks',
+        n=n,
+        random_seed=random_seed,
+    )
 
 
 def generate_block_ranges(
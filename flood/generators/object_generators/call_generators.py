@@ -1,15 +1,47 @@
 from __future__ import annotations
 
+import functools
 import typing
 
 import flood
 from flood import generators
+from .. import networks
 from . import address_generators
 from . import block_generators
+from . import chain_head
+from . import lazy_calls
 from . import slot_generators
 from . import transaction_generators
 
 
+def _generate_block_numbers(
+    n: int,
+    *,
+    kind: str,
+    network: str | None,
+    random_seed: flood.RandomSeed | None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
+) -> typing.Sequence[int | str]:
+    """select blocks relative to chain head if blocks is given, otherwise
+    within the network's default block range of kind"""
+    if blocks is not None:
+        return chain_head.generate_head_relative_block_numbers(
+            n,
+            blocks=blocks,
+            rpc_url=rpc_url,
+            random_seed=random_seed,
+        )
+    start_block, end_block = networks.get_network_block_range(network, kind)
+    return block_generators.generate_block_numbers(
+        n=n,
+        start_block=start_block,
+        end_block=end_block,
+        random_seed=random_seed,
+        network=network,
+    )
+
+
 #
 # # blocks
 #
@@ -19,25 +51,28 @@ def generate_calls_eth_get_block_by_number(
     n_calls: int | None = None,
     *,
     network: str | None = None,
-    block_numbers: typing.Sequence[int] | None = None,
+    block_numbers: typing.Sequence[int | str] | None = None,
     random_seed: flood.RandomSeed | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
 ) -> typing.Sequence[flood.Call]:
     import ctc.rpc
 
     if block_numbers is None:
         if n_calls is None:
             raise Exception('must floodify more parameters')
-        block_numbers = block_generators.generate_block_numbers(
+        block_numbers = _generate_block_numbers(
             n=n_calls,
             random_seed=random_seed,
-            start_block=0,
-            end_block=16_000_000,
+            kind='blocks',
             network=network,
+            blocks=blocks,
+            rpc_url=rpc_url,
         )
-    return [
-        ctc.rpc.construct_eth_get_block_by_number(block_number=block_number)
-        for block_number in block_numbers
-    ]
+    return lazy_calls.LazyCalls(
+        ctc.rpc.construct_eth_get_block_by_number,
+        block_number=block_numbers,
+    )
 
 
 def generate_calls_eth_get_block_by_hash(
@@ -57,10 +92,10 @@ def generate_calls_eth_get_block_by_hash(
             network=network,
             random_seed=random_seed,
         )
-    return [
-        ctc.rpc.construct_eth_get_block_by_hash(block_hash=block_hash)
-        for block_hash in block_hashes
-    ]
+    return lazy_calls.LazyCalls(
+        ctc.rpc.construct_eth_get_block_by_hash,
+        block_hash=block_hashes,
+    )
 
 
 def generate_calls_eth_fee_history(
@@ -68,31 +103,34 @@ def generate_calls_eth_fee_history(
     *,
     network: str | None = None,
     random_seed: flood.RandomSeed | None = None,
-    block_numbers: typing.Sequence[int] | None = None,
-    block_count: int | None = None
+    block_numbers: typing.Sequence[int | str] | None = None,
+    block_count: int | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
 ) -> typing.Sequence[flood.Call]:
     import ctc.rpc
 
     if block_numbers is None:
         if n_calls is None:
             raise Exception('must floodify more parameters')
-        block_numbers = block_generators.generate_block_numbers(
+        block_numbers = _generate_block_numbers(
             n=n_calls,
             random_seed=random_seed,
-            start_block=13_000_000,
-            end_block=17_000_000,
+            kind='fees',
             network=network,
+            blocks=blocks,
+            rpc_url=rpc_url,
         )
     if block_count is None:
         block_count = 1024
 
-    return [
-        ctc.rpc.construct_eth_fee_history(
-            block_number,
+    return lazy_calls.LazyCalls(
+        functools.partial(
+            ctc.rpc.construct_eth_fee_history,
             block_count=block_count,
-        )
-        for block_number in block_numbers
-    ]
+        ),
+        block_numbers,
+    )
 
 
 #
@@ -105,20 +143,23 @@ def generate_calls_eth_get_eth_balance(
     *,
     network: str,
     addresses: typing.Sequence[str] | None = None,
-    block_numbers: typing.Sequence[int] | None = None,
+    block_numbers: typing.Sequence[int | str] | None = None,
     random_seed: flood.RandomSeed | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
 ) -> typing.Sequence[flood.Call]:
     import ctc.rpc
 
     if block_numbers is None:
         if n_calls is None:
             raise Exception('must floodify more parameters')
-        block_numbers = block_generators.generate_block_numbers(
-            start_block=10_000_000,
-            end_block=16_000_000,
+        block_numbers = _generate_block_numbers(
+            kind='state',
             n=n_calls,
             random_seed=random_seed,
             network=network,
+            blocks=blocks,
+            rpc_url=rpc_url,
         )
     if addresses is None:
         if n_calls is None:
@@ -129,12 +170,11 @@ def generate_calls_eth_get_eth_balance(
             random_seed=random_seed,
         )
 
-    return [
-        ctc.rpc.construct_eth_get_balance(
-            address=address, block_number=block_number
-        )
-        for address, block_number in zip(addresses, block_numbers)
-    ]
+    return lazy_calls.LazyCalls(
+        ctc.rpc.construct_eth_get_balance,
+        address=addresses,
+        block_number=block_numbers,
+    )
 
 
 def generate_calls_eth_get_transaction_count(
@@ -142,7 +182,7 @@ def generate_calls_eth_get_transaction_count(
     *,
     network: str,
     addresses: typing.Sequence[str] | None = None,
-    block_numbers: typing.Sequence[int] | None = None,
+    block_numbers: typing.Sequence[int | str] | None = None,
     random_seed: flood.RandomSeed | None = None,
 ) -> typing.Sequence[flood.Call]:
     import ctc.rpc
@@ -150,9 +190,8 @@ def generate_calls_eth_get_transaction_count(
     if block_numbers is None:
         if n_calls is None:
             raise Exception('must floodify more parameters')
-        block_numbers = block_generators.generate_block_numbers(
-            start_block=10_000_000,
-            end_block=16_000_000,
+        block_numbers = _generate_block_numbers(
+            kind='state',
             n=n_calls,
             random_seed=random_seed,
             network=network,
@@ -166,12 +205,11 @@ def generate_calls_eth_get_transaction_count(
             random_seed=random_seed,
         )
 
-    return [
-        ctc.rpc.construct_eth_get_transaction_count(
-            from_address=address, block_number=block_number
-        )
-        for address, block_number in zip(addresses, block_numbers)
-    ]
+    return lazy_calls.LazyCalls(
+        ctc.rpc.construct_eth_get_transaction_count,
+        from_address=addresses,
+        block_number=block_numbers,
+    )
 
 
 #
@@ -196,12 +234,10 @@ def generate_calls_eth_get_transaction_by_hash(
             network=network,
             random_seed=random_seed,
         )
-    return [
-        ctc.rpc.construct_eth_get_transaction_by_hash(
-            transaction_hash=transaction_hash
-        )
-        for transaction_hash in transaction_hashes
-    ]
+    return lazy_calls.LazyCalls(
+        ctc.rpc.construct_eth_get_transaction_by_hash,
+        transaction_hash=transaction_hashes,
+    )
 
 
 def generate_calls_eth_get_transaction_receipt(
@@ -221,24 +257,16 @@ def generate_calls_eth_get_transaction_receipt(
             network=network,
             random_seed=random_seed,
         )
-    return [
-        ctc.rpc.construct_eth_get_transaction_receipt(
-            transaction_hash=transaction_hash
-        )
-        for transaction_hash in transaction_hashes
-    ]
+    return lazy_calls.LazyCalls(
+        ctc.rpc.construct_eth_get_transaction_receipt,
+        transaction_hash=transaction_hashes,
+    )
 
 
 #
 # # logs
 #
 
-_default_contracts = {
-    'USDC': '0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48',
-    'DAI': '0x6b175474e89094c44da98b954eedeac495271d0f',
-    'LUSD': '0x5f98805a4e8be255a32880fdec7f6728c6568ba0',
-}
-
 _default_event_hashes = {
     'Transfer': '0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef',  # noqa: E501
     'Approval': '0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925',  # noqa: E501
@@ -254,35 +282,59 @@ def generate_calls_eth_get_logs(
     block_range_size: int | None = None,
     network: str | None = None,
     random_seed: flood.RandomSeed | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
 ) -> typing.Sequence[flood.Call]:
-    import ctc.rpc
-
     if contract_address is None:
-        contract_address = _default_contracts['USDC']
+        contract_address = networks.get_network_contracts(network)['USDC']
     if block_ranges is None:
         if n_calls is None:
             raise Exception('must floodify more parameters')
         if block_range_size is None:
             block_range_size = 100
-        block_ranges = block_generators.generate_block_ranges(
-            start_block=10_000_000,
-            end_block=16_000_000,
-            n=n_calls,
-            range_size=block_range_size,
-            random_seed=random_seed,
-            network=network,
-        )
+        if blocks is not None:
+            block_ranges = chain_head.generate_head_relative_block_ranges(
+                n_calls,
+                range_size=block_range_size,
+                blocks=blocks,
+                rpc_url=rpc_url,
+                random_seed=random_seed,
+            )
+        else:
+            start_block, end_block = networks.get_network_block_range(
+                network, 'state'
+            )
+            block_ranges = block_generators.generate_block_ranges(
+                start_block=start_block,
+                end_block=end_block,
+                n=n_calls,
+                range_size=block_range_size,
+                random_seed=random_seed,
+                network=network,
+            )
     if topics is None:
         topics = [_default_event_hashes['Transfer']]
-    return [
-        ctc.rpc.construct_eth_get_logs(
+    return lazy_calls.LazyCalls(
+        functools.partial(
+            _construct_eth_get_logs,
             address=contract_address,
             topics=topics,
-            start_block=start_block,
-            end_block=end_block,
-        )
-        for start_block, end_block in block_ranges
-    ]
+        ),
+        block_range=block_ranges,
+    )
+
+
+def _construct_eth_get_logs(
+    block_range: tuple[int, int], **kwargs: typing.Any
+) -> flood.Call:
+    """construct eth_getLogs call, ranges of head-relative blocks are only
+    resolved here"""
+    import ctc.rpc
+
+    start_block, end_block = block_range
+    return ctc.rpc.construct_eth_get_logs(  # type: ignore
+        start_block=start_block, end_block=end_block, **kwargs
+    )
 
 
 #
@@ -295,8 +347,7 @@ def generate_calls_eth_get_code(
     *,
     network: str,
     addresses: typing.Sequence[str] | None = None,
-    block_numbers: typing.Sequence[int | typing.Literal['latest']]
-    | None = None,
+    block_numbers: typing.Sequence[int | str] | None = None,
     random_seed: flood.RandomSeed | None = None,
 ) -> typing.Sequence[flood.Call]:
     import ctc.rpc
@@ -304,9 +355,8 @@ def generate_calls_eth_get_code(
     if block_numbers is None:
         if n_calls is None:
             raise Exception('must floodify more parameters')
-        block_numbers = block_generators.generate_block_numbers(
-            start_block=10_000_000,
-            end_block=16_000_000,
+        block_numbers = _generate_block_numbers(
+            kind='state',
             n=n_calls,
             random_seed=random_seed,
             network=network,
@@ -319,12 +369,11 @@ def generate_calls_eth_get_code(
             network=network,
             random_seed=random_seed,
         )
-    return [
-        ctc.rpc.construct_eth_get_code(
-            address=address, block_number=block_number
-        )
-        for address, block_number in zip(addresses, block_numbers)
-    ]
+    return lazy_calls.LazyCalls(
+        ctc.rpc.construct_eth_get_code,
+        address=addresses,
+        block_number=block_numbers,
+    )
 
 
 def generate_calls_eth_get_storage_at(
@@ -332,8 +381,7 @@ def generate_calls_eth_get_storage_at(
     *,
     network: str,
     slots: typing.Sequence[tuple[str, str]] | None = None,
-    block_numbers: typing.Sequence[int | typing.Literal['latest']]
-    | None = None,
+    block_numbers: typing.Sequence[int | str] | None = None,
     random_seed: flood.RandomSeed | None = None,
 ) -> typing.Sequence[flood.Call]:
     import ctc.rpc
@@ -341,9 +389,8 @@ def generate_calls_eth_get_storage_at(
     if block_numbers is None:
         if n_calls is None:
             raise Exception('must floodify more parameters')
-        block_numbers = block_generators.generate_block_numbers(
-            start_block=10_000_000,
-            end_block=16_000_000,
+        block_numbers = _generate_block_numbers(
+            kind='state',
             n=n_calls,
             random_seed=random_seed,
             network=network,
@@ -354,12 +401,12 @@ def generate_calls_eth_get_storage_at(
         slots = slot_generators.generate_slots(
             n_calls, network=network, random_seed=random_seed
         )
-    return [
-        ctc.rpc.construct_eth_get_storage_at(
-            address=address, position=slot, block_number=block_number
-        )
-        for (address, slot), block_number in zip(slots, block_numbers)
-    ]
+    return lazy_calls.LazyCalls(
+        ctc.rpc.construct_eth_get_storage_at,
+        address=[address for address, _ in slots],
+        position=[slot for _, slot in slots],
+        block_number=block_numbers,
+    )
 
 
 _default_call_datas = {
@@ -376,36 +423,28 @@ def generate_calls_eth_call(
 ) -> typing.Sequence[flood.Call]:
     import ctc.rpc
 
-    if network != 'ethereum':
-        raise Exception('only ethereum supported for eth_call')
-
     rng = generators.get_rng(random_seed=random_seed)
     contract_addresses = rng.choice(
-        list(_default_contracts.values()),
+        list(networks.get_network_contracts(network).values()),
         size=n_calls,
     )
     call_datas = rng.choice(
         list(_default_call_datas.values()),
         size=n_calls,
     )
-    block_numbers = block_generators.generate_block_numbers(
-        start_block=10_000_000,
-        end_block=16_000_000,
+    block_numbers = _generate_block_numbers(
+        kind='state',
         n=n_calls,
         random_seed=random_seed,
         network=network,
     )
 
-    return [
-        ctc.rpc.construct_eth_call(
-            to_address=contract_address,
-            call_data=call_data,
-            block_number=block_number,
-        )
-        for contract_address, call_data, block_number in zip(
-            contract_addresses, call_datas, block_numbers
-        )
-    ]
+    return lazy_calls.LazyCalls(
+        ctc.rpc.construct_eth_call,
+        to_address=contract_addresses,
+        call_data=call_datas,
+        block_number=block_numbers,
+    )
 
 
 #
@@ -416,26 +455,29 @@ def generate_calls_eth_call(
 def generate_calls_trace_block(
     n_calls: int | None = None,
     *,
-    block_numbers: typing.Sequence[int] | None = None,
+    block_numbers: typing.Sequence[int | str] | None = None,
     network: str | None = None,
     random_seed: flood.RandomSeed | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
 ) -> typing.Sequence[flood.Call]:
     import ctc.rpc
 
     if block_numbers is None:
         if n_calls is None:
             raise Exception('must floodify more parameters')
-        block_numbers = block_generators.generate_block_numbers(
+        block_numbers = _generate_block_numbers(
             n=n_calls,
             random_seed=0,
-            start_block=0,
-            end_block=16_000_000,
+            kind='blocks',
             network=network,
+            blocks=blocks,
+            rpc_url=rpc_url,
         )
-    return [
-        ctc.rpc.construct_trace_block(block_number=block_number)
-        for block_number in block_numbers
-    ]
+    return lazy_calls.LazyCalls(
+        ctc.rpc.construct_trace_block,
+        block_number=block_numbers,
+    )
 
 
 def generate_calls_trace_transaction(
@@ -457,94 +499,103 @@ def generate_calls_trace_transaction(
             network=network,
             random_seed=random_seed,
         )
-    return [
-        ctc.rpc.construct_trace_transaction(transaction_hash=transaction_hash)
-        for transaction_hash in transaction_hashes
-    ]
+    return lazy_calls.LazyCalls(
+        ctc.rpc.construct_trace_transaction,
+        transaction_hash=transaction_hashes,
+    )
 
 
 def generate_calls_trace_replay_block_transactions(
     n_calls: int | None = None,
     *,
-    block_numbers: typing.Sequence[int] | None = None,
+    block_numbers: typing.Sequence[int | str] | None = None,
     network: str | None = None,
     random_seed: flood.RandomSeed | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
 ) -> typing.Sequence[flood.Call]:
     import ctc.rpc
 
     if block_numbers is None:
         if n_calls is None:
             raise Exception('must floodify more parameters')
-        block_numbers = block_generators.generate_block_numbers(
+        block_numbers = _generate_block_numbers(
             n=n_calls,
             random_seed=random_seed,
-            start_block=0,
-            end_block=16_000_000,
+            kind='blocks',
             network=network,
+            blocks=blocks,
+            rpc_url=rpc_url,
         )
-    return [
-        ctc.rpc.construct_trace_replay_block_transactions(
-            block_number=block_number,
+    return lazy_calls.LazyCalls(
+        functools.partial(
+            ctc.rpc.construct_trace_replay_block_transactions,
             trace_type=['trace'],
-        )
-        for block_number in block_numbers
-    ]
+        ),
+        block_number=block_numbers,
+    )
 
 
 def generate_calls_trace_replay_block_transactions_state_diff(
     n_calls: int | None = None,
     *,
-    block_numbers: typing.Sequence[int] | None = None,
+    block_numbers: typing.Sequence[int | str] | None = None,
     network: str | None = None,
     random_seed: flood.RandomSeed | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
 ) -> typing.Sequence[flood.Call]:
     import ctc.rpc
 
     if block_numbers is None:
         if n_calls is None:
             raise Exception('must floodify more parameters')
-        block_numbers = block_generators.generate_block_numbers(
+        block_numbers = _generate_block_numbers(
             n=n_calls,
             random_seed=random_seed,
-            start_block=0,
-            end_block=16_000_000,
+            kind='blocks',
             network=network,
+            blocks=blocks,
+            rpc_url=rpc_url,
         )
-    return [
-        ctc.rpc.construct_trace_replay_block_transactions(
-            block_number=block_number,
+    return lazy_calls.LazyCalls(
+        functools.partial(
+            ctc.rpc.construct_trace_replay_block_transactions,
             trace_type=['stateDiff'],
-        )
-        for block_number in block_numbers
-    ]
+        ),
+        block_number=block_numbers,
+    )
 
 
 def generate_calls_trace_replay_block_transactions_vm_trace(
     n_calls: int | None = None,
     *,
-    block_numbers: typing.Sequence[int] | None = None,
+    block_numbers: typing.Sequence[int | str] | None = None,
     network: str | None = None,
     random_seed: flood.RandomSeed | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
 ) -> typing.Sequence[flood.Call]:
     import ctc.rpc
 
     if block_numbers is None:
         if n_calls is None:
             raise Exception('must floodify more parameters')
-        block_numbers = block_generators.generate_block_numbers(
+        block_numbers = _generate_block_numbers(
             n=n_calls,
             random_seed=random_seed,
-            start_block=0,
-            end_block=16_000_000,
+            kind='blocks',
             network=network,
+            blocks=blocks,
+            rpc_url=rpc_url,
         )
-    return [
-        ctc.rpc.construct_trace_replay_block_transactions(
-            block_number=block_number,
+    return lazy_calls.LazyCalls(
+        functools.partial(
+            ctc.rpc.construct_trace_replay_block_transactions,
             trace_type=['vmTrace'],
-        )
-        for block_number in block_numbers
-    ]
+        ),
+        block_number=block_numbers,
+    )
 
 
 def generate_calls_trace_replay_transaction(
@@ -566,13 +617,13 @@ def generate_calls_trace_replay_transaction(
             random_seed=random_seed,
             network=network,
         )
-    return [
-        ctc.rpc.construct_trace_replay_transaction(
-            transaction_hash=transaction_hash,
+    return lazy_calls.LazyCalls(
+        functools.partial(
+            ctc.rpc.construct_trace_replay_transaction,
             trace_type=['trace'],
-        )
-        for transaction_hash in transaction_hashes
-    ]
+        ),
+        transaction_hash=transaction_hashes,
+    )
 
 
 def generate_calls_trace_replay_transaction_state_diff(
@@ -594,13 +645,13 @@ def generate_calls_trace_replay_transaction_state_diff(
             random_seed=random_seed,
             network=network,
         )
-    return [
-        ctc.rpc.construct_trace_replay_transaction(
-            transaction_hash=transaction_hash,
+    return lazy_calls.LazyCalls(
+        functools.partial(
+            ctc.rpc.construct_trace_replay_transaction,
             trace_type=['stateDiff'],
-        )
-        for transaction_hash in transaction_hashes
-    ]
+        ),
+        transaction_hash=transaction_hashes,
+    )
 
 
 def generate_calls_trace_replay_transaction_vm_trace(
@@ -622,10 +673,103 @@ def generate_calls_trace_replay_transaction_vm_trace(
             random_seed=random_seed,
             network=network,
         )
-    return [
-        ctc.rpc.construct_trace_replay_transaction(
-            transaction_hash=transaction_hash,
+    return lazy_calls.LazyCalls(
+        functools.partial(
+            ctc.rpc.construct_trace_replay_transaction,
             trace_type=['vmTrace'],
-        )
-        for transaction_hash in transaction_hashes
+        ),
+        transaction_hash=transaction_hashes,
+    )
+
+
+#
+# # mixed workloads
+#
+
+default_mixed_weights = {
+    'eth_call': 40,
+    'eth_getLogs': 30,
+    'eth_getBalance': 20,
+    'trace_block': 10,
+}
+
+_call_generator_names = {
+    'eth_getBalance': 'generate_calls_eth_get_eth_balance',
+}
+
+
+def get_call_generator(
+    method: str,
+) -> typing.Callable[..., typing.Sequence[flood.Call]]:
+    """get generate_calls_* function of RPC method, e.g. eth_getLogs"""
+    import re
+
+    if method in _call_generator_names:
+        function_name = _call_generator_names[method]
+    else:
+        snake_case = re.sub(r'(?<!^)(?=[A-Z])', '_', method).lower()
+        function_name = 'generate_calls_' + snake_case
+    if not hasattr(generators, function_name):
+        raise Exception('no call generator for method: ' + str(method))
+    return getattr(generators, function_name)  # type: ignore
+
+
+def generate_calls_mixed(
+    n_calls: int,
+    *,
+    weights: typing.Mapping[str, float] | None = None,
+    network: str,
+    random_seed: flood.RandomSeed | None = None,
+) -> typing.Sequence[flood.Call]:
+    """generate calls of multiple methods, shuffled together
+
+    weights map RPC method names to their relative share of calls
+    """
+    if weights is None:
+        weights = default_mixed_weights
+    if len(weights) == 0:
+        raise Exception('must specify at least one method')
+    if any(weight < 0 for weight in weights.values()):
+        raise Exception('weights must be non-negative')
+    total_weight = sum(weights.values())
+    if total_weight <= 0:
+        raise Exception('weights must sum to a positive number')
+
+    # allocate calls by largest remainder so that counts sum to n_calls
+    quotas = {
+        method: n_calls * weight / total_weight
+        for method, weight in weights.items()
+    }
+    counts = {method: int(quota) for method, quota in quotas.items()}
+    remainders = sorted(
+        weights.keys(),
+        key=lambda method: quotas[method] - counts[method],
+        reverse=True,
+    )
+    for method in remainders[: n_calls - sum(counts.values())]:
+        counts[method] += 1
+
+    parts: list[typing.Sequence[flood.Call]] = []
+    for method, count in counts.items():
+        if count > 0:
+            call_generator = get_call_generator(method)
+            parts.append(
+                call_generator(
+                    n_calls=count,
+                    network=network,
+                    random_seed=random_seed,
+                )
+            )
+
+    # shuffle (part, index) pairs so that calls are still constructed lazily
+    part_numbers = [
+        p for p, part in enumerate(parts) for _ in range(len(part))
     ]
+    part_indices = [i for part in parts for i in range(len(part))]
+    rng = generators.get_rng(random_seed=random_seed)
+    order = rng.permutation(len(part_numbers))
+    return lazy_calls.LazyCalls(
+        lambda part_number, index: parts[part_number][index],
+        [part_numbers[index] for index in order],
+        [part_indices[index] for index in order],
+    )
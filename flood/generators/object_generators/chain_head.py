@@ -0,0 +1,302 @@
+"""select blocks relative to the current chain head of a node
+
+blocks are selected either as a block tag that the node resolves itself,
+e.g. 'latest', or as a number of recent blocks, e.g. 128 for the last 128
+blocks behind the head
+
+recent blocks are stored as offsets behind the head and only resolved when
+each call is constructed, so calls follow the tip as it advances during long
+tests, the head used by each call is recorded so that calls constructed again
+for deep checks match the calls that were sent
+"""
+from __future__ import annotations
+
+import typing
+
+from flood import spec
+from .. import rng_utils
+
+if typing.TYPE_CHECKING:
+    import threading
+
+
+block_tags: typing.Sequence[str] = ['latest', 'safe', 'finalized']
+
+# seconds that a polled block number is reused before polling again
+default_chain_head_refresh = 1.0
+
+_chain_heads: dict[tuple[str | None, str], ChainHead] = {}
+
+
+class ChainHead:
+    """block number of a node's block tag, polled as the tip advances"""
+
+    def __init__(
+        self,
+        rpc_url: str | None,
+        *,
+        tag: str = 'latest',
+        refresh_interval: float | None = None,
+    ) -> None:
+        import threading
+
+        if tag not in block_tags:
+            raise Exception('unknown block tag: ' + str(tag))
+        if refresh_interval is None:
+            refresh_interval = default_chain_head_refresh
+        self.rpc_url = rpc_url
+        self.tag = tag
+        self.refresh_interval = refresh_interval
+        self._block_number: int | None = None
+        self._t_polled: float | None = None
+        self._lock: threading.Lock = threading.Lock()
+
+    def get_block_number(self) -> int:
+        import time
+
+        with self._lock:
+            now = time.time()
+            if (
+                self._block_number is None
+                or self._t_polled is None
+                or now - self._t_polled >= self.refresh_interval
+            ):
+                self._block_number = self._poll_block_number()
+                self._t_polled = now
+            return self._block_number
+
+    def _poll_block_number(self) -> int:
+        import json
+        import requests
+
+        if self.rpc_url is None:
+            raise Exception('must specify rpc_url to select blocks from head')
+        if self.tag == 'latest':
+            call: typing.Mapping[str, typing.Any] = {
+                'jsonrpc': '2.0',
+                'method': 'eth_blockNumber',
+                'params': [],
+                'id': 1,
+            }
+        else:
+            call = {
+                'jsonrpc': '2.0',
+                'method': 'eth_getBlockByNumber',
+                'params': [self.tag, False],
+                'id': 1,
+            }
+        if self.rpc_url.startswith(('ws://', 'wss://')):
+            response = _request_over_websocket(self.rpc_url, call)
+        elif self.rpc_url.startswith(('http://', 'https://')):
+            response = requests.post(
+                url=self.rpc_url,
+                data=json.dumps(call),
+                headers={
+                    'Content-Type': 'application/json',
+                    'User-Agent': 'flood',
+                },
+                timeout=10,
+            ).json()
+        else:
+            raise Exception('invalid rpc_url: ' + str(self.rpc_url))
+        result = response.get('result')
+        if self.tag != 'latest' and isinstance(result, dict):
+            result = result.get('number')
+        if not isinstance(result, str):
+            raise Exception('could not get ' + self.tag + ' block of node')
+        return int(result, 16)
+
+
+def get_chain_head(rpc_url: str | None, tag: str = 'latest') -> ChainHead:
+    """get chain head of node, shared by all generators of the process"""
+    key = (rpc_url, tag)
+    if key not in _chain_heads:
+        _chain_heads[key] = ChainHead(rpc_url, tag=tag)
+    return _chain_heads[key]
+
+
+def _request_over_websocket(
+    url: str,
+    call: typing.Mapping[str, typing.Any],
+    timeout: float = 10,
+) -> typing.Any:
+    """send a single call over a new websocket connection"""
+    import asyncio
+    import concurrent.futures
+
+    async def request() -> typing.Any:
+        import aiohttp
+
+        client_timeout = aiohttp.ClientTimeout(total=timeout)
+        async with aiohttp.ClientSession(timeout=client_timeout) as session:
+            async with session.ws_connect(url) as ws:
+                await ws.send_json(call)
+                return await ws.receive_json(timeout=timeout)
+
+    # run in its own thread, calls may be constructed inside an event loop
+    with concurrent.futures.ThreadPoolExecutor(max_workers=1) as executor:
+        return executor.submit(asyncio.run, request()).result()
+
+
+def _create_resolved_heads(n: int) -> memoryview:
+    """create storage of the head used by each item, -1 until resolved"""
+    import array
+
+    return memoryview(array.array('q', [-1]) * n)
+
+
+class HeadRelativeBlocks(typing.Sequence[int]):
+    """block numbers given as offsets behind a chain head
+
+    each block number is resolved from the head when it is first accessed,
+    later accesses reuse the same head
+    """
+
+    def __init__(
+        self,
+        offsets: typing.Sequence[int],
+        chain_head: ChainHead,
+        _heads: memoryview | None = None,
+    ) -> None:
+        if _heads is None:
+            _heads = _create_resolved_heads(len(offsets))
+        self._offsets = offsets
+        self.chain_head = chain_head
+        self._heads = _heads
+
+    def __len__(self) -> int:
+        return len(self._offsets)
+
+    @typing.overload
+    def __getitem__(self, item: int) -> int:
+        ...
+
+    @typing.overload
+    def __getitem__(self, item: slice) -> HeadRelativeBlocks:
+        ...
+
+    def __getitem__(self, item: int | slice) -> int | HeadRelativeBlocks:
+        if isinstance(item, slice):
+            return HeadRelativeBlocks(
+                self._offsets[item], self.chain_head, self._heads[item]
+            )
+        head = _get_resolved_head(self._heads, item, self.chain_head)
+        return max(head - self._offsets[item], 0)
+
+
+class HeadRelativeBlockRanges(typing.Sequence[typing.Tuple[int, int]]):
+    """block ranges that end at offsets behind a chain head
+
+    both ends of each range are resolved from the same head when the range
+    is first accessed, later accesses reuse the same head
+    """
+
+    def __init__(
+        self,
+        offsets: typing.Sequence[int],
+        range_size: int,
+        chain_head: ChainHead,
+        _heads: memoryview | None = None,
+    ) -> None:
+        if _heads is None:
+            _heads = _create_resolved_heads(len(offsets))
+        self._offsets = offsets
+        self.range_size = range_size
+        self.chain_head = chain_head
+        self._heads = _heads
+
+    def __len__(self) -> int:
+        return len(self._offsets)
+
+    @typing.overload
+    def __getitem__(self, item: int) -> tuple[int, int]:
+        ...
+
+    @typing.overload
+    def __getitem__(self, item: slice) -> HeadRelativeBlockRanges:
+        ...
+
+    def __getitem__(
+        self, item: int | slice
+    ) -> tuple[int, int] | HeadRelativeBlockRanges:
+        if isinstance(item, slice):
+            return HeadRelativeBlockRanges(
+                self._offsets[item],
+                self.range_size,
+                self.chain_head,
+                self._heads[item],
+            )
+        head = _get_resolved_head(self._heads, item, self.chain_head)
+        end_block = max(head - self._offsets[item], 0)
+        return (max(end_block - self.range_size, 0), end_block)
+
+
+def _get_resolved_head(
+    heads: memoryview, item: int, chain_head: ChainHead
+) -> int:
+    """get head used by item, resolving it from chain_head on first access"""
+    head: int = heads[item]
+    if head < 0:
+        head = chain_head.get_block_number()
+        heads[item] = head
+    return head
+
+
+def parse_block_selection(blocks: str | int) -> spec.BlockSelection:
+    """parse block tag or number of recent blocks, e.g. 'safe' or '128'"""
+    if isinstance(blocks, str) and blocks in block_tags:
+        return blocks  # type: ignore
+    try:
+        n_blocks = int(blocks)
+    except ValueError:
+        raise Exception(
+            'blocks should be one of '
+            + ', '.join(block_tags)
+            + ' or a number of recent blocks'
+        )
+    if n_blocks < 1:
+        raise Exception('number of recent blocks must be at least 1')
+    return n_blocks
+
+
+def generate_head_relative_block_numbers(
+    n: int,
+    *,
+    blocks: spec.BlockSelection,
+    rpc_url: str | None = None,
+    random_seed: spec.RandomSeed | None = None,
+) -> typing.Sequence[int | str]:
+    """generate block numbers relative to chain head of node at rpc_url
+
+    block tags are passed to the node as is
+    """
+    if isinstance(blocks, str):
+        if blocks not in block_tags:
+            raise Exception('unknown block tag: ' + str(blocks))
+        return [blocks] * n
+    rng = rng_utils.get_rng(random_seed=random_seed)
+    offsets: list[int] = rng.integers(0, blocks, size=n).tolist()
+    return HeadRelativeBlocks(offsets, get_chain_head(rpc_url))
+
+
+def generate_head_relative_block_ranges(
+    n: int,
+    *,
+    range_size: int,
+    blocks: spec.BlockSelection,
+    rpc_url: str | None = None,
+    random_seed: spec.RandomSeed | None = None,
+) -> typing.Sequence[tuple[int, int]]:
+    """generate block ranges relative to chain head of node at rpc_url
+
+    ranges of a block tag end at the tag's block, ranges of recent blocks
+    fall within those blocks when there are more blocks than range_size
+    """
+    if isinstance(blocks, str):
+        return HeadRelativeBlockRanges(
+            [0] * n, range_size, get_chain_head(rpc_url, tag=blocks)
+        )
+    rng = rng_utils.get_rng(random_seed=random_seed)
+    max_offset = max(blocks - range_size, 1)
+    offsets: list[int] = rng.integers(0, max_offset, size=n).tolist()
+    return HeadRelativeBlockRanges(offsets, range_size, get_chain_head(rpc_url))
@@ -0,0 +1,73 @@
+from __future__ import annotations
+
+import typing
+
+import flood
+
+
+class LazyCalls(typing.Sequence[typing.Any]):
+    """sequence of calls that are only constructed when accessed
+
+    stores constructor arguments instead of calls so that long tests do not
+    hold millions of calls in memory, each argument is a sequence with one
+    item per call, arguments are truncated to the shortest like zip()
+
+    the id of each call is its index in the original sequence, so calls keep
+    the same id when constructed more than once or when sliced into attacks
+    """
+
+    def __init__(
+        self,
+        constructor: typing.Callable[..., flood.Call],
+        *args: typing.Sequence[typing.Any],
+        **kwargs: typing.Sequence[typing.Any],
+    ) -> None:
+        lengths = [len(arg) for arg in args]
+        lengths.extend(len(kwarg) for kwarg in kwargs.values())
+        if len(lengths) == 0:
+            raise Exception('must specify at least one argument')
+        self._constructor = constructor
+        self._args = args
+        self._kwargs = kwargs
+        self._indices = range(min(lengths))
+
+    def __len__(self) -> int:
+        return len(self._indices)
+
+    @typing.overload
+    def __getitem__(self, item: int) -> flood.Call:
+        ...
+
+    @typing.overload
+    def __getitem__(self, item: slice) -> LazyCalls:
+        ...
+
+    def __getitem__(self, item: int | slice) -> flood.Call | LazyCalls:
+        if isinstance(item, slice):
+            sliced = object.__new__(LazyCalls)
+            sliced._constructor = self._constructor
+            sliced._args = self._args
+            sliced._kwargs = self._kwargs
+            sliced._indices = self._indices[item]
+            return sliced
+        index = self._indices[item]
+        call = dict(
+            self._constructor(
+                *[arg[index] for arg in self._args],
+                **{key: value[index] for key, value in self._kwargs.items()},
+            )
+        )
+        call['id'] = index
+        return call  # type: ignore
+
+    def get_position(self, call_id: typing.Any) -> int | None:
+        """get position of the call with id, None if not in this sequence"""
+        if not isinstance(call_id, int) or isinstance(call_id, bool):
+            return None
+        if call_id not in self._indices:
+            return None
+        return self._indices.index(call_id)
+
+    def __iter__(self) -> typing.Iterator[flood.Call]:
+        for i in range(len(self._indices)):
+            yield self[i]
@@ -0,0 +1,95 @@
+from __future__ import annotations
+
+import typing
+
+import flood
+
+
+def load_traffic_log(
+    path: str,
+) -> tuple[typing.Sequence[flood.Call], typing.Sequence[float]]:
+    """load JSONL log of RPC requests, one request per line
+
+    each line has a method, params, and a timestamp, timestamps can be unix
+    seconds or ISO 8601 strings
+
+    returns calls sorted by timestamp and their offsets in seconds relative to
+    the first request, call ids are reassigned so that they are unique
+    """
+    import json
+
+    requests = []
+    with open(path, 'r') as f:
+        for line_number, line in enumerate(f, start=1):
+            line = line.strip()
+            if line == '':
+                continue
+            try:
+                request = json.loads(line)
+                method = request['method']
+                params = request.get('params', [])
+                timestamp = _parse_log_timestamp(request['timestamp'])
+            except Exception:
+                raise Exception(
+                    'invalid traffic log entry on line ' + str(line_number)
+                )
+            requests.append((timestamp, method, params))
+    if len(requests) == 0:
+        raise Exception('traffic log is empty')
+    requests.sort(key=lambda request: request[0])
+
+    t_start = requests[0][0]
+    calls = []
+    offsets = []
+    for r, (timestamp, method, params) in enumerate(requests):
+        call = {'jsonrpc': '2.0', 'method': method, 'params': params, 'id': r}
+        calls.append(call)
+        offsets.append(timestamp - t_start)
+    return calls, offsets
+
+
+def _parse_log_timestamp(timestamp: typing.Any) -> float:
+    import datetime
+
+    if isinstance(timestamp, (int, float)):
+        return float(timestamp)
+    elif isinstance(timestamp, str):
+        if timestamp.endswith('Z'):
+            timestamp = timestamp[:-1] + '+00:00'
+        dt = datetime.datetime.fromisoformat(timestamp)
+        if dt.tzinfo is None:
+            dt = dt.replace(tzinfo=datetime.timezone.utc)
+        return dt.timestamp()
+    else:
+        raise Exception('invalid timestamp: ' + str(timestamp))
+
+
+def generate_replay_attack(
+    path: str,
+    *,
+    speed: float | None = None,
+    vegeta_args: flood.VegetaArgs = None,
+) -> flood.VegetaAttack:
+    """create attack that replays traffic log with its original timing
+
+    speed > 1 compresses the inter-arrival times, speed < 1 stretches them
+    """
+    import math
+
+    if speed is None:
+        speed = 1.0
+    if speed <= 0:
+        raise Exception('speed must be positive')
+
+    calls, offsets = load_traffic_log(path)
+    offsets = [offset / speed for offset in offsets]
+    duration = max(1, math.ceil(offsets[-1]))
+    return {
+        'rate': max(1, round(len(calls) / duration)),
+        'duration': duration,
+        'calls': calls,
+        'vegeta_args': vegeta_args,
+        'concurrency': None,
+        'think_time': None,
+        'offsets': offsets,
+    }
@@ -19,6 +19,8 @@ default_spike_durations = {
 }
 default_soak_test_rate = 100
 default_soak_test_duration = 24 * 60 * 60
+default_concurrency_test_concurrencies = [1, 4, 16, 64, 256]
+default_concurrency_test_duration = 30
 
 
 def generate_timings(
@@ -27,7 +29,10 @@ def generate_timings(
     durations: typing.Sequence[int] | None = None,
     mode: flood.LoadTestMode | None = None,
 ) -> tuple[typing.Sequence[int], typing.Sequence[int]]:
-    """create rates and durations for test"""
+    """create rates and durations for test
+
+    for concurrency mode, rates are the number of concurrent workers
+    """
 
     if mode is None:
         mode = 'stress'
@@ -50,6 +55,12 @@ def generate_timings(
             duration=duration,
             durations=durations,
         )
+    elif mode == 'concurrency':
+        return _generate_timings_for_concurrency_test(
+            concurrencies=rates,
+            duration=duration,
+            durations=durations,
+        )
     else:
         raise Exception('unknown mode: ' + str(mode))
 
@@ -143,3 +154,19 @@ def _generate_timings_for_soak_test(
             raise Exception('must specify 1 duration for soak test')
 
     return rates, durations
+
+
+def _generate_timings_for_concurrency_test(
+    concurrencies: typing.Sequence[int] | None = None,
+    duration: int | None = None,
+    durations: typing.Sequence[int] | None = None,
+) -> tuple[typing.Sequence[int], typing.Sequence[int]]:
+    if concurrencies is None:
+        concurrencies = default_concurrency_test_concurrencies
+    if any(concurrency < 1 for concurrency in concurrencies):
+        raise Exception('concurrency must be at least 1')
+    if durations is None:
+        if duration is None:
+            duration = default_concurrency_test_duration
+        durations = [duration] * len(concurrencies)
+    return concurrencies, durations
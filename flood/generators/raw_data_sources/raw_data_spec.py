@@ -18,6 +18,7 @@ default_sizes = {
     'XL': 10_000_000,
 }
 
+# blocks are only used by eth_getBlockByHash tests, so they are opt-in
 default_datatypes = [
     'contracts',
     'eoas',
@@ -49,7 +49,7 @@ def create_samples_dataset(
     # collect samples
     samples = _create_raw_samples(
         n=max_size,
-        network='ethereum',
+        network=network,
         datatypes=datatypes,
     )
 
@@ -83,6 +83,38 @@ def _create_raw_samples(
 
     samples = {}
 
+    # blocks
+    if 'blocks' in datatypes:
+        blocks_glob = pdp.get_dataset_glob(
+            network=network,
+            datatype='blocks',
+        )
+        blocks_df = (
+            pl.scan_parquet(blocks_glob)
+            .select('block_number', 'block_hash', 'gas_used')
+            .collect()
+        )
+        if len(blocks_df) > n:
+            blocks_df = blocks_df.sample(n)
+        transactions_glob = pdp.get_dataset_glob(
+            network=network,
+            datatype='transactions',
+        )
+        n_transactions = (
+            pl.scan_parquet(transactions_glob)
+            .select('block_number')
+            .filter(pl.col('block_number').is_in(blocks_df['block_number']))
+            .groupby('block_number')
+            .agg(pl.count().alias('n_transactions'))
+            .collect()
+        )
+        samples['blocks'] = (
+            blocks_df.join(n_transactions, on='block_number', how='left')
+            .with_columns(pl.col('n_transactions').fill_null(0))
+            .select('block_number', 'block_hash', 'n_transactions', 'gas_used')
+            .sample(n, with_replacement=len(blocks_df) < n)
+        )
+
     # contracts
     if 'contracts' in datatypes:
         contracts_df = contracts.query_contracts(
@@ -121,13 +121,22 @@ def load_samples(
         if download_missing:
             if size is None:
                 size = 'L'
-            raw_download_utils.download_raw_data(
-                network=network,
-                datatypes=[datatype],
-                version=version,
-                output_dir=samples_dir,
-                sizes=[size],
-            )
+            try:
+                raw_download_utils.download_raw_data(
+                    network=network,
+                    datatypes=[datatype],
+                    version=version,
+                    output_dir=samples_dir,
+                    sizes=[size],
+                )
+            except Exception:
+                raise Exception(
+                    'could not download '
+                    + datatype
+                    + ' samples of network '
+                    + network
+                    + ', use `flood samples collect` to create them'
+                )
             path = get_raw_samples_path(
                 datatype=datatype,
                 network=network,
@@ -141,6 +150,7 @@ def load_samples(
             raise Exception('no raw samples found to load')
 
     columns = {
+        'blocks': ['block_hash'],
         'contracts': ['contract_address'],
         'eoas': ['eoa'],
         'transactions': ['transaction_hash'],
@@ -13,15 +13,26 @@ def generate_test_eth_get_balance(
     durations: typing.Sequence[int] | None = None,
     network: str,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
     random_seed: flood.RandomSeed | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
     n_calls = load_tests.estimate_call_count(
-        rates=rates, duration=duration, durations=durations
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
     )
     calls = flood.generators.generate_calls_eth_get_eth_balance(
         n_calls=n_calls,
         network=network,
         random_seed=random_seed,
+        blocks=blocks,
+        rpc_url=rpc_url,
     )
     return load_tests.create_load_test(
         calls=calls,
@@ -29,6 +40,9 @@ def generate_test_eth_get_balance(
         duration=duration,
         durations=durations,
         vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
     )
 
 
@@ -39,10 +53,17 @@ def generate_test_eth_get_transaction_count(
     durations: typing.Sequence[int] | None = None,
     network: str,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
     random_seed: flood.RandomSeed | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
     n_calls = load_tests.estimate_call_count(
-        rates=rates, duration=duration, durations=durations
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
     )
     calls = flood.generators.generate_calls_eth_get_transaction_count(
         n_calls=n_calls,
@@ -55,4 +76,7 @@ def generate_test_eth_get_transaction_count(
         duration=duration,
         durations=durations,
         vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
     )
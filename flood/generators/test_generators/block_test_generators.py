@@ -14,15 +14,26 @@ def generate_test_eth_get_block_by_number(
     durations: typing.Sequence[int] | None = None,
     network: str,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
     random_seed: spec.RandomSeed | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
     n_calls = load_tests.estimate_call_count(
-        rates=rates, duration=duration, durations=durations
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
     )
     calls = flood.generators.generate_calls_eth_get_block_by_number(
         n_calls=n_calls,
         network=network,
         random_seed=random_seed,
+        blocks=blocks,
+        rpc_url=rpc_url,
     )
     return load_tests.create_load_test(
         calls=calls,
@@ -30,22 +41,32 @@ def generate_test_eth_get_block_by_number(
         duration=duration,
         durations=durations,
         vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
     )
 
 
-def generate_test_eth_fee_history(
+def generate_test_eth_get_block_by_hash(
     *,
     rates: typing.Sequence[int],
     duration: int | None = None,
     durations: typing.Sequence[int] | None = None,
     network: str,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
     random_seed: spec.RandomSeed | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
     n_calls = load_tests.estimate_call_count(
-        rates=rates, duration=duration, durations=durations
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
     )
-    calls = flood.generators.generate_calls_eth_fee_history(
+    calls = flood.generators.generate_calls_eth_get_block_by_hash(
         n_calls=n_calls,
         network=network,
         random_seed=random_seed,
@@ -56,30 +77,47 @@ def generate_test_eth_fee_history(
         duration=duration,
         durations=durations,
         vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
     )
 
 
-# def generate_test_eth_get_block_by_hash(
-#     *,
-#     rates: typing.Sequence[int],
-#     duration: int | None = None,
-#     durations: typing.Sequence[int] | None = None,
-#     network: str,
-#     vegeta_args: typing.Mapping[str, str | None] | None = None,
-#     random_seed: spec.RandomSeed | None = None,
-# ) -> typing.Sequence[flood.VegetaAttack]:
-#     n_calls = load_tests.estimate_call_count(
-#         rates=rates, duration=duration, durations=durations
-#     )
-#     calls = flood.generate_calls_eth_get_block_by_hash(
-#         n_calls=n_calls,
-#         network=network,
-#         random_seed=random_seed,
-#     )
-#     return load_tests.create_load_test(
-#         calls=calls,
-#         rates=rates,
-#         duration=duration,
-#         durations=durations,
-        # vegeta_args=vegeta_args,
-#     )
+def generate_test_eth_fee_history(
+    *,
+    rates: typing.Sequence[int],
+    duration: int | None = None,
+    durations: typing.Sequence[int] | None = None,
+    network: str,
+    vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
+    random_seed: spec.RandomSeed | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
+) -> typing.Sequence[flood.VegetaAttack]:
+    n_calls = load_tests.estimate_call_count(
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
+    )
+    calls = flood.generators.generate_calls_eth_fee_history(
+        n_calls=n_calls,
+        network=network,
+        random_seed=random_seed,
+        blocks=blocks,
+        rpc_url=rpc_url,
+    )
+    return load_tests.create_load_test(
+        calls=calls,
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
+    )
@@ -13,10 +13,17 @@ def generate_test_eth_get_code(
     duration: int | None = None,
     durations: typing.Sequence[int] | None = None,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
     random_seed: flood.RandomSeed | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
     n_calls = load_tests.estimate_call_count(
-        rates=rates, duration=duration, durations=durations
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
     )
     calls = flood.generators.generate_calls_eth_get_code(
         n_calls=n_calls,
@@ -29,6 +36,9 @@ def generate_test_eth_get_code(
         duration=duration,
         durations=durations,
         vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
     )
 
 
@@ -39,10 +49,17 @@ def generate_test_eth_get_storage_at(
     duration: int | None = None,
     durations: typing.Sequence[int] | None = None,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
     random_seed: flood.RandomSeed | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
     n_calls = load_tests.estimate_call_count(
-        rates=rates, duration=duration, durations=durations
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
     )
     calls = flood.generators.generate_calls_eth_get_storage_at(
         n_calls=n_calls,
@@ -55,6 +72,9 @@ def generate_test_eth_get_storage_at(
         duration=duration,
         durations=durations,
         vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
     )
 
 
@@ -65,10 +85,17 @@ def generate_test_eth_call(
     duration: int | None = None,
     durations: typing.Sequence[int] | None = None,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
     random_seed: flood.RandomSeed | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
     n_calls = load_tests.estimate_call_count(
-        rates=rates, duration=duration, durations=durations
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
     )
     calls = flood.generators.generate_calls_eth_call(
         n_calls=n_calls,
@@ -81,4 +108,7 @@ def generate_test_eth_call(
         duration=duration,
         durations=durations,
         vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
     )
@@ -65,10 +65,26 @@ def generate_test(
     network: str,
     # output_dir: str | None = None,
     flood_version: str,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
+    weights: typing.Mapping[str, float] | None = None,
+    replay_path: str | None = None,
+    replay_speed: float | None = None,
+    loader_index: int | None = None,
+    n_loaders: int | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
 ) -> flood.LoadTest:
+    """if n_loaders is given, only generate share of test for loader_index
+
+    if blocks is given, blocks are selected relative to the chain head of the
+    node at rpc_url, which is polled as calls are constructed
+    """
     if test_name is None:
         raise Exception('must specify test_name')
-    test_generator = get_test_generator(test_name)
+    if mode == 'search':
+        raise Exception('search tests are generated adaptively while running')
     test_parameters: flood.TestGenerationParameters = {
         'flood_version': flood.get_flood_version(),
         'test_name': test_name,
@@ -77,15 +93,103 @@ def generate_test(
         'durations': durations,
         'vegeta_args': vegeta_args,
         'network': network,
+        'batch_size': batch_size,
+        'mode': mode,
+        'think_time': think_time,
+        'weights': weights,
+        'replay_path': replay_path,
+        'replay_speed': replay_speed,
+        'loader_index': loader_index,
+        'n_loaders': n_loaders,
+        'blocks': blocks,
     }
+    if (loader_index is None) != (n_loaders is None):
+        raise Exception('must specify both loader_index and n_loaders')
+
+    # replayed tests use calls and timing of a traffic log
+    if replay_path is not None:
+        if isinstance(vegeta_args, (list, tuple)):
+            raise Exception('replay tests have a single attack')
+        attack = flood.generators.generate_replay_attack(
+            replay_path,
+            speed=replay_speed,
+            vegeta_args=vegeta_args,  # type: ignore
+        )
+        attacks = [attack]
+    else:
+        attacks = _generate_test_attacks(
+            test_name=test_name,
+            random_seed=random_seed,
+            rates=rates,
+            durations=durations,
+            vegeta_args=vegeta_args,
+            network=network,
+            batch_size=batch_size,
+            mode=mode,
+            think_time=think_time,
+            weights=weights,
+            blocks=blocks,
+            rpc_url=rpc_url,
+        )
+
+    # distributed tests send a share of each attack from each loader
+    if loader_index is not None and n_loaders is not None:
+        attacks = [
+            flood.tests.load_tests.split_attack(
+                attack, loader_index=loader_index, n_loaders=n_loaders
+            )
+            for attack in attacks
+        ]
+
+    return {'attacks': attacks, 'test_parameters': test_parameters}
+
+
+def _generate_test_attacks(
+    *,
+    test_name: str,
+    random_seed: flood.RandomSeed | None,
+    rates: typing.Sequence[int] | None,
+    durations: typing.Sequence[int] | None,
+    vegeta_args: flood.VegetaArgsShorthand | None,
+    network: str,
+    batch_size: int | None,
+    mode: flood.LoadTestMode | None,
+    think_time: float | None,
+    weights: typing.Mapping[str, float] | None,
+    blocks: flood.BlockSelection | None,
+    rpc_url: str | None,
+) -> typing.Sequence[flood.VegetaAttack]:
+    import inspect
+
+    test_generator = get_test_generator(test_name)
+
+    # only mixed workload tests take weights
+    extra_kwargs: dict[str, typing.Any] = {}
+    if weights is not None:
+        extra_kwargs['weights'] = weights
+
+    # only tests of block numbers or ranges take head-relative blocks
+    if blocks is not None:
+        parameters = inspect.signature(test_generator).parameters
+        if 'blocks' not in parameters:
+            raise Exception(
+                'test does not support selecting blocks: ' + test_name
+            )
+        extra_kwargs['blocks'] = blocks
+        extra_kwargs['rpc_url'] = rpc_url
+
     attacks = test_generator(
         rates=rates,
         durations=durations,
         vegeta_args=vegeta_args,
         network=network,
         random_seed=random_seed,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
+        **extra_kwargs,
     )
-    return {'attacks': attacks, 'test_parameters': test_parameters}
+    return attacks
 
 
 def generate_tests(
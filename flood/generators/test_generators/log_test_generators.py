@@ -14,12 +14,21 @@ def generate_test_eth_get_logs(
     duration: int | None = None,
     durations: typing.Sequence[int] | None = None,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
     random_seed: flood.RandomSeed | None = None,
     contract_address: str | None = None,
     block_range_size: int | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
     n_calls = load_tests.estimate_call_count(
-        rates=rates, duration=duration, durations=durations
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
     )
     calls = flood.generators.generate_calls_eth_get_logs(
         n_calls,
@@ -27,6 +36,8 @@ def generate_test_eth_get_logs(
         random_seed=random_seed,
         contract_address=contract_address,
         block_range_size=block_range_size,
+        blocks=blocks,
+        rpc_url=rpc_url,
     )
     return load_tests.create_load_test(
         calls=calls,
@@ -34,6 +45,9 @@ def generate_test_eth_get_logs(
         duration=duration,
         durations=durations,
         vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
     )
 
 
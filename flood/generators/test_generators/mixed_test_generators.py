@@ -0,0 +1,45 @@
+from __future__ import annotations
+
+import typing
+
+import flood
+from flood.tests import load_tests
+
+
+def generate_test_mixed_workload(
+    *,
+    rates: typing.Sequence[int],
+    duration: int | None = None,
+    durations: typing.Sequence[int] | None = None,
+    network: str,
+    vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
+    random_seed: flood.RandomSeed | None = None,
+    weights: typing.Mapping[str, float] | None = None,
+) -> typing.Sequence[flood.VegetaAttack]:
+    """weights map RPC method names to their relative share of calls"""
+    n_calls = load_tests.estimate_call_count(
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
+    )
+    calls = flood.generators.generate_calls_mixed(
+        n_calls=n_calls,
+        weights=weights,
+        network=network,
+        random_seed=random_seed,
+    )
+    return load_tests.create_load_test(
+        calls=calls,
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
+    )
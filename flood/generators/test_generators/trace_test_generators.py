@@ -13,15 +13,26 @@ def generate_test_trace_block(
     duration: int | None = None,
     durations: typing.Sequence[int] | None = None,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
     random_seed: flood.RandomSeed | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
     n_calls = load_tests.estimate_call_count(
-        rates=rates, duration=duration, durations=durations
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
     )
     calls = flood.generators.generate_calls_trace_block(
         n_calls=n_calls,
         network=network,
         random_seed=random_seed,
+        blocks=blocks,
+        rpc_url=rpc_url,
     )
     return load_tests.create_load_test(
         calls=calls,
@@ -29,6 +40,9 @@ def generate_test_trace_block(
         duration=duration,
         durations=durations,
         vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
     )
 
 
@@ -39,10 +53,17 @@ def generate_test_trace_transaction(
     duration: int | None = None,
     durations: typing.Sequence[int] | None = None,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
     random_seed: flood.RandomSeed | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
     n_calls = load_tests.estimate_call_count(
-        rates=rates, duration=duration, durations=durations
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
     )
     calls = flood.generators.generate_calls_trace_transaction(
         n_calls=n_calls,
@@ -55,6 +76,9 @@ def generate_test_trace_transaction(
         duration=duration,
         durations=durations,
         vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
     )
 
 
@@ -65,15 +89,26 @@ def generate_test_trace_replay_block_transactions(
     duration: int | None = None,
     durations: typing.Sequence[int] | None = None,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
     random_seed: flood.RandomSeed | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
     n_calls = load_tests.estimate_call_count(
-        rates=rates, duration=duration, durations=durations
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
     )
     calls = flood.generators.generate_calls_trace_replay_block_transactions(
         n_calls=n_calls,
         network=network,
         random_seed=random_seed,
+        blocks=blocks,
+        rpc_url=rpc_url,
     )
     return load_tests.create_load_test(
         calls=calls,
@@ -81,6 +116,9 @@ def generate_test_trace_replay_block_transactions(
         duration=duration,
         durations=durations,
         vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
     )
 
 
@@ -91,15 +129,26 @@ def generate_test_trace_replay_block_transactions_state_diff(
     duration: int | None = None,
     durations: typing.Sequence[int] | None = None,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
     random_seed: flood.RandomSeed | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
     n_calls = load_tests.estimate_call_count(
-        rates=rates, duration=duration, durations=durations
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
     )
     calls = flood.generators.generate_calls_trace_replay_block_transactions_state_diff(  # noqa: E501
         n_calls=n_calls,
         network=network,
         random_seed=random_seed,
+        blocks=blocks,
+        rpc_url=rpc_url,
     )
     return load_tests.create_load_test(
         calls=calls,
@@ -107,6 +156,9 @@ def generate_test_trace_replay_block_transactions_state_diff(
         duration=duration,
         durations=durations,
         vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
     )
 
 
@@ -117,15 +169,26 @@ def generate_test_trace_replay_block_transactions_vm_trace(
     duration: int | None = None,
     durations: typing.Sequence[int] | None = None,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
     random_seed: flood.RandomSeed | None = None,
+    blocks: flood.BlockSelection | None = None,
+    rpc_url: str | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
     n_calls = load_tests.estimate_call_count(
-        rates=rates, duration=duration, durations=durations
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
     )
     calls = flood.generators.generate_calls_trace_replay_block_transactions_vm_trace(  # noqa: E501
         n_calls=n_calls,
         network=network,
         random_seed=random_seed,
+        blocks=blocks,
+        rpc_url=rpc_url,
     )
     return load_tests.create_load_test(
         calls=calls,
@@ -133,6 +196,9 @@ def generate_test_trace_replay_block_transactions_vm_trace(
         duration=duration,
         durations=durations,
         vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
     )
 
 
@@ -143,10 +209,17 @@ def generate_test_trace_replay_transaction(
     duration: int | None = None,
     durations: typing.Sequence[int] | None = None,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
     random_seed: flood.RandomSeed | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
     n_calls = load_tests.estimate_call_count(
-        rates=rates, duration=duration, durations=durations
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
     )
     calls = flood.generators.generate_calls_trace_replay_transaction(
         n_calls=n_calls,
@@ -159,6 +232,9 @@ def generate_test_trace_replay_transaction(
         duration=duration,
         durations=durations,
         vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
     )
 
 
@@ -169,10 +245,17 @@ def generate_test_trace_replay_transaction_state_diff(
     duration: int | None = None,
     durations: typing.Sequence[int] | None = None,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
     random_seed: flood.RandomSeed | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
     n_calls = load_tests.estimate_call_count(
-        rates=rates, duration=duration, durations=durations
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
     )
     calls = flood.generators.generate_calls_trace_replay_transaction_state_diff(
         n_calls=n_calls,
@@ -185,6 +268,9 @@ def generate_test_trace_replay_transaction_state_diff(
         duration=duration,
         durations=durations,
         vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
     )
 
 
@@ -195,10 +281,17 @@ def generate_test_trace_replay_transaction_vm_trace(
     duration: int | None = None,
     durations: typing.Sequence[int] | None = None,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
     random_seed: flood.RandomSeed | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
     n_calls = load_tests.estimate_call_count(
-        rates=rates, duration=duration, durations=durations
+        rates=rates,
+        duration=duration,
+        durations=durations,
+        batch_size=batch_size,
+        mode=mode,
     )
     calls = flood.generators.generate_calls_trace_replay_transaction_vm_trace(
         n_calls=n_calls,
@@ -211,5 +304,8 @@ def generate_test_trace_replay_transaction_vm_trace(
         duration=duration,
         durations=durations,
         vegeta_args=vegeta_args,
+        batch_size=batch_size,
+        mode=mode,
+        think_time=think_time,
     )
 
@@ -16,8 +16,10 @@ def get_flood_version() -> str:
 
 
 def get_local_installation() -> flood.FloodInstallation:
-    result = subprocess.check_output(['which', 'vegeta'])
-    vegeta_path = result.decode().rstrip()
+    import shutil
+
+    # vegeta is optional when using the native load engine
+    vegeta_path = shutil.which('vegeta')
 
     flood_version = flood.__version__
 
@@ -110,6 +112,7 @@ def get_dependency_versions() -> typing.Mapping[str, str | None]:
 
     versions = {}
     for module_name in [
+        'aiohttp',
         'ctc',
         'ipykernel',
         'ipython_genutils',
@@ -20,14 +20,38 @@ def run(
     durations: typing.Sequence[int] | None = None,
     mode: flood.LoadTestMode | None = None,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    think_time: float | None = None,
+    slo: flood.ThroughputSLO | None = None,
+    weights: typing.Mapping[str, float] | None = None,
+    blocks: flood.BlockSelection | None = None,
     dry: bool = False,
     output_dir: str | None = None,
     figures: bool = True,
     metrics: typing.Sequence[str] | None = None,
     include_deep_output: typing.Sequence[flood.DeepOutput] | None = None,
     deep_check: bool = False,
+    engine: flood.LoadEngine | None = None,
+    parallel: bool = False,
+    stop_conditions: flood.StopConditions | None = None,
+    loaders: typing.Sequence[str] | None = None,
+    start_time: float | None = None,
+    node_metrics_urls: typing.Mapping[str, str] | None = None,
 ) -> flood.RunOutput:
-    """generate and run tests against nodes"""
+    """generate and run tests against nodes
+
+    if parallel, all local nodes are tested at the same time
+
+    if any stop condition is met after an attack, remaining attacks are
+    skipped and recorded as not run
+
+    if loaders are given, each attack is split across those remote hosts
+
+    if blocks is given, blocks are selected relative to each node's chain head
+
+    node_metrics_urls are prometheus endpoints scraped during each attack,
+    given by node name
+    """
     import os
 
     # get output_dir
@@ -55,6 +79,12 @@ def run(
             figures=figures,
             include_deep_output=include_deep_output,
             deep_check=deep_check,
+            engine=engine,
+            parallel=parallel,
+            stop_conditions=stop_conditions,
+            loaders=loaders,
+            start_time=start_time,
+            node_metrics_urls=node_metrics_urls,
         )
         return {'single_run': output}
 
@@ -68,7 +98,13 @@ def run(
                 rates=rates,
                 duration=duration,
                 durations=durations,
+                mode=mode,
                 vegeta_args=vegeta_args,
+                batch_size=batch_size,
+                think_time=think_time,
+                slo=slo,
+                weights=weights,
+                blocks=blocks,
                 #
                 test_name=test_name,
                 nodes=nodes,
@@ -80,6 +116,12 @@ def run(
                 figures=figures,
                 include_deep_output=include_deep_output,
                 deep_check=deep_check,
+                engine=engine,
+                parallel=parallel,
+                stop_conditions=stop_conditions,
+                loaders=loaders,
+                start_time=start_time,
+                node_metrics_urls=node_metrics_urls,
             )
             return {'single_run': output}
         elif test_name in generators.get_multi_test_generators():
@@ -88,6 +130,48 @@ def run(
             raise Exception('invalid test name')
 
 
+def replay(
+    path: str,
+    *,
+    nodes: flood.NodesShorthand,
+    speed: float | None = None,
+    random_seed: flood.RandomSeed | None = None,
+    verbose: bool | int = True,
+    vegeta_args: flood.VegetaArgsShorthand | None = None,
+    dry: bool = False,
+    output_dir: str | None = None,
+    figures: bool = True,
+    metrics: typing.Sequence[str] | None = None,
+    include_deep_output: typing.Sequence[flood.DeepOutput] | None = None,
+    deep_check: bool = False,
+    engine: flood.LoadEngine | None = None,
+) -> flood.RunOutput:
+    """replay traffic log against nodes, speed scales the request timing"""
+    import os
+
+    if not os.path.isfile(path):
+        raise Exception('traffic log does not exist: ' + str(path))
+
+    output = single_runner_execution._run_single(
+        replay_path=os.path.abspath(os.path.expanduser(path)),
+        replay_speed=speed,
+        vegeta_args=vegeta_args,
+        #
+        test_name='replay',
+        nodes=nodes,
+        random_seed=random_seed,
+        dry=dry,
+        output_dir=_get_output_dir(output_dir),
+        verbose=verbose,
+        metrics=metrics,
+        figures=figures,
+        include_deep_output=include_deep_output,
+        deep_check=deep_check,
+        engine=engine,
+    )
+    return {'single_run': output}
+
+
 def _get_output_dir(output_dir: str | None) -> str:
     import os
 
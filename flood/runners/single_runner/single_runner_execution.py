@@ -19,6 +19,13 @@ def _run_single(
     durations: typing.Sequence[int] | None = None,
     mode: flood.LoadTestMode | None = None,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    think_time: float | None = None,
+    slo: flood.ThroughputSLO | None = None,
+    weights: typing.Mapping[str, float] | None = None,
+    blocks: flood.BlockSelection | None = None,
+    replay_path: str | None = None,
+    replay_speed: float | None = None,
     dry: bool,
     output_dir: str,
     figures: bool,
@@ -26,6 +33,12 @@ def _run_single(
     verbose: bool | int,
     include_deep_output: typing.Sequence[flood.DeepOutput] | None = None,
     deep_check: bool = False,
+    engine: flood.LoadEngine | None = None,
+    parallel: bool = False,
+    stop_conditions: flood.StopConditions | None = None,
+    loaders: typing.Sequence[str] | None = None,
+    start_time: float | None = None,
+    node_metrics_urls: typing.Mapping[str, str] | None = None,
 ) -> flood.SingleRunOutput:
     import time
 
@@ -37,14 +50,22 @@ def _run_single(
         include_deep_output = list(include_deep_output) + ['metrics']
 
     # get test parameters
-    rates, durations, vegeta_args = _get_single_test_parameters(
+    rates, durations, vegeta_args, mode = _get_single_test_parameters(
         test=test,
         rates=rates,
         duration=duration,
         durations=durations,
         mode=mode,
         vegeta_args=vegeta_args,
+        replay_path=replay_path,
+        replay_speed=replay_speed,
     )
+    if parallel and mode == 'search':
+        raise Exception('parallel not supported in search mode')
+    if stop_conditions is not None and mode == 'search':
+        raise Exception('stop conditions not used in search mode, use slo')
+    if loaders is not None and mode == 'search':
+        raise Exception('loaders not supported in search mode')
 
     # print preamble
     if verbose:
@@ -55,11 +76,16 @@ def _run_single(
             durations=durations,
             vegeta_args=vegeta_args,
             output_dir=output_dir,
+            mode=mode,
+            slo=slo,
         )
 
     # parse nodes
     nodes = flood.user_io.parse_nodes(
-        nodes, verbose=verbose, request_metadata=True
+        nodes,
+        verbose=verbose,
+        request_metadata=True,
+        metrics_urls=node_metrics_urls,
     )
 
     # generate test and save to disk
@@ -74,6 +100,15 @@ def _run_single(
             'vegeta_args': vegeta_args,
             'network': flood.user_io.parse_nodes_network(nodes),
             'random_seed': random_seed,
+            'batch_size': batch_size,
+            'mode': mode,
+            'think_time': think_time,
+            'weights': weights,
+            'replay_path': replay_path,
+            'replay_speed': replay_speed,
+            'loader_index': None,
+            'n_loaders': None,
+            'blocks': blocks,
         }
         flood.runners.single_runner.single_runner_io._save_single_run_test(
             test_name=test_name,
@@ -94,12 +129,28 @@ def _run_single(
     # run tests
     if verbose:
         single_runner_summary._print_run_start()
-    results = flood.run_load_tests(
-        nodes=nodes,
-        test=use_test,
-        verbose=verbose,
-        include_deep_output=include_deep_output,
-    )
+    throughput_search = None
+    if mode == 'search':
+        results, throughput_search = _run_throughput_searches(
+            nodes=nodes,
+            test_parameters=test_parameters,
+            slo=slo,
+            verbose=verbose,
+            include_deep_output=include_deep_output,
+            engine=engine,
+        )
+    else:
+        results = flood.run_load_tests(
+            nodes=nodes,
+            test=use_test,
+            verbose=verbose,
+            include_deep_output=include_deep_output,
+            engine=engine,
+            parallel=parallel,
+            stop_conditions=stop_conditions,
+            loaders=loaders,
+            start_time=start_time,
+        )
 
     # output results to file
     payload = single_runner_io._save_single_run_results(
@@ -110,6 +161,7 @@ def _run_single(
         test_name=test_name,
         t_run_start=t_start,
         t_run_end=time.time(),
+        throughput_search=throughput_search,
     )
 
     # print summary
@@ -121,6 +173,7 @@ def _run_single(
             verbose=verbose,
             figures=figures,
             deep_check=deep_check,
+            throughput_search=throughput_search,
         )
 
     return {
@@ -138,16 +191,44 @@ def _get_single_test_parameters(
     durations: typing.Sequence[int] | None = None,
     mode: flood.LoadTestMode | None = None,
     vegeta_args: flood.VegetaArgsShorthand | None = None,
+    replay_path: str | None = None,
+    replay_speed: float | None = None,
 ) -> tuple[
     typing.Sequence[int],
     typing.Sequence[int],
     flood.VegetaArgsShorthand | None,
+    flood.LoadTestMode | None,
 ]:
     if test is not None:
         test_data = flood.user_io.parse_test_data(test=test)
         rates = test_data['rates']
         durations = test_data['durations']
         vegeta_args = test_data['vegeta_args']
+        if any(
+            concurrency is not None
+            for concurrency in test_data['concurrencies']
+        ):
+            mode = 'concurrency'
+            rates = [
+                concurrency if concurrency is not None else 0
+                for concurrency in test_data['concurrencies']
+            ]
+    elif replay_path is not None:
+        attack = flood.generators.generate_replay_attack(
+            replay_path, speed=replay_speed
+        )
+        rates = [attack['rate']]
+        durations = [attack['duration']]
+    elif mode == 'search':
+        # rates are [start_rate] or [start_rate, max_rate]
+        search = flood.tests.load_tests.throughput_search
+        if rates is None:
+            rates = [search.default_search_start_rate]
+        elif len(rates) not in [1, 2]:
+            raise Exception('search mode takes a start rate and max rate')
+        if duration is None:
+            duration = search.default_search_duration
+        durations = [duration]
     else:
         rates, durations = flood.generators.generate_timings(
             rates=rates,
@@ -155,5 +236,54 @@ def _get_single_test_parameters(
             durations=durations,
             mode=mode,
         )
-    return rates, durations, vegeta_args
+    return rates, durations, vegeta_args, mode
+
+
+def _run_throughput_searches(
+    *,
+    nodes: flood.Nodes,
+    test_parameters: flood.TestGenerationParameters,
+    slo: flood.ThroughputSLO | None,
+    verbose: bool | int,
+    include_deep_output: typing.Sequence[flood.DeepOutput],
+    engine: flood.LoadEngine | None,
+) -> tuple[
+    typing.Mapping[str, flood.LoadTestOutput],
+    typing.Mapping[str, flood.ThroughputSearchSummary],
+]:
+    rates = test_parameters['rates']
+    durations = test_parameters['durations']
+    if rates is None or durations is None:
+        raise Exception('search mode requires start rate and duration')
+    if len(rates) == 2:
+        max_rate: int | None = rates[1]
+    else:
+        max_rate = None
 
+    results = {}
+    summaries = {}
+    for name, node in nodes.items():
+        if verbose:
+            flood.user_io.print_timestamped(
+                'Searching max sustainable rate for ' + name
+            )
+        output = flood.tests.load_tests.run_throughput_search(
+            node=node,
+            test_name=test_parameters['test_name'],
+            slo=slo,
+            start_rate=rates[0],
+            max_rate=max_rate,
+            duration=durations[0],
+            network=test_parameters['network'],
+            random_seed=test_parameters['random_seed'],
+            vegeta_args=test_parameters['vegeta_args'],
+            batch_size=test_parameters.get('batch_size'),
+            weights=test_parameters.get('weights'),
+            blocks=test_parameters.get('blocks'),
+            include_deep_output=include_deep_output,
+            engine=engine,
+            verbose=verbose,
+        )
+        results[name] = output['results']
+        summaries[name] = output['summary']
+    return results, summaries
@@ -4,6 +4,9 @@ import typing
 
 import flood
 
+if typing.TYPE_CHECKING:
+    import polars as pl
+
 
 #
 # # path utiltiies
@@ -13,6 +16,7 @@ _path_templates = {
     'single_run_test': '{output_dir}/test.json',
     'single_run_results': '{output_dir}/results.json',
     'single_run_figures_dir': '{output_dir}/figures',
+    'single_run_raw_output': '{output_dir}/raw_outputs/{node_name}.parquet',
 }
 
 
@@ -30,6 +34,12 @@ def get_single_run_figures_path(output_dir: str) -> str:
     )
 
 
+def get_single_run_raw_output_path(output_dir: str, node_name: str) -> str:
+    return _path_templates['single_run_raw_output'].format(
+        output_dir=output_dir, node_name=node_name
+    )
+
+
 #
 # # save utilities
 #
@@ -69,6 +79,8 @@ def _save_single_run_results(
     test_name: str,
     t_run_start: float,
     t_run_end: float,
+    throughput_search: typing.Mapping[str, flood.ThroughputSearchSummary]
+    | None = None,
 ) -> flood.SingleRunResultsPayload:
     import os
     import sys
@@ -81,6 +93,11 @@ def _save_single_run_results(
         else:
             os.makedirs(output_dir)
 
+    # raw outputs are saved as parquet files instead of inside results.json
+    results = _save_single_run_raw_outputs(
+        output_dir=output_dir, results=results
+    )
+
     path = _path_templates['single_run_results'].format(output_dir=output_dir)
     payload: flood.SingleRunResultsPayload = {
         'flood_version': flood.get_flood_version(),
@@ -91,6 +108,7 @@ def _save_single_run_results(
         't_run_end': t_run_end,
         'nodes': nodes,
         'results': results,
+        'throughput_search': throughput_search,
     }
     with open(path, 'wb') as f:
         f.write(orjson.dumps(payload))
@@ -108,6 +126,25 @@ def _save_single_run_results(
     return payload
 
 
+def _save_single_run_raw_outputs(
+    *,
+    output_dir: str,
+    results: typing.Mapping[str, flood.LoadTestOutput],
+) -> typing.Mapping[str, flood.LoadTestOutput]:
+    """save raw outputs of each node, return results without raw outputs"""
+    stripped = {}
+    for name, result in results.items():
+        raw_outputs = result.get('deep_raw_output')
+        if raw_outputs is not None:
+            path = get_single_run_raw_output_path(
+                output_dir=output_dir, node_name=name
+            )
+            flood.tests.load_tests.save_raw_outputs(raw_outputs, path)
+            result = dict(result, deep_raw_output=None)  # type: ignore
+        stripped[name] = result
+    return stripped
+
+
 #
 # # load utiltiies
 #
@@ -152,3 +189,35 @@ def load_single_run_results_payload(
         results: flood.SingleRunResultsPayload = orjson.loads(f.read())
     return results
 
+
+def load_single_run_raw_output(
+    output_dir: str,
+    node_name: str | None = None,
+    attack_index: int | None = None,
+) -> pl.DataFrame:
+    """load raw responses of a node as a dataframe, 1 row per response
+
+    node_name can be omitted if only a single node was tested
+    """
+    import glob
+    import os
+
+    if node_name is None:
+        pattern = get_single_run_raw_output_path(
+            output_dir=output_dir, node_name='*'
+        )
+        paths = glob.glob(pattern)
+        if len(paths) == 0:
+            raise Exception('no raw outputs saved in ' + output_dir)
+        elif len(paths) > 1:
+            raise Exception('multiple nodes in output_dir, specify node_name')
+        path = paths[0]
+    else:
+        path = get_single_run_raw_output_path(
+            output_dir=output_dir, node_name=node_name
+        )
+        if not os.path.isfile(path):
+            raise Exception('no raw outputs saved for node ' + node_name)
+    return flood.tests.load_tests.load_raw_outputs(
+        path, attack_index=attack_index
+    )
@@ -47,6 +47,8 @@ def _print_single_run_preamble_copy(
     vegeta_args: flood.VegetaArgsShorthand | None,
     rerun_of: str | None = None,
     output_dir: str | None,
+    mode: flood.LoadTestMode | None = None,
+    slo: flood.ThroughputSLO | None = None,
 ) -> None:
     import toolstr
 
@@ -56,7 +58,30 @@ def _print_single_run_preamble_copy(
         toolstr.add_style('Load test: ' + test_name, styles['metavar']),
         style=flood.user_io.styles['content'],
     )
-    toolstr.print_bullet(key='sample rates', value=rates, styles=styles)
+    if mode == 'concurrency':
+        toolstr.print_bullet(
+            key='sample concurrencies', value=rates, styles=styles
+        )
+    elif mode == 'search':
+        if slo is None:
+            slo = flood.tests.load_tests.throughput_search.default_slo
+        toolstr.print_bullet(
+            key='search start rate', value=rates[0], styles=styles
+        )
+        if len(rates) > 1:
+            toolstr.print_bullet(
+                key='search max rate', value=rates[1], styles=styles
+            )
+        toolstr.print_bullet(
+            key='SLO',
+            value='p99 <= '
+            + str(slo['max_p99'])
+            + 's, success >= '
+            + str(slo['min_success']),
+            styles=styles,
+        )
+    else:
+        toolstr.print_bullet(key='sample rates', value=rates, styles=styles)
     if len(set(durations)) == 1:
         toolstr.print_bullet(
             key='sample duration',
@@ -100,6 +125,8 @@ def _print_single_run_conclusion(
     verbose: bool | int,
     figures: bool,
     deep_check: bool,
+    throughput_search: typing.Mapping[str, flood.ThroughputSearchSummary]
+    | None = None,
 ) -> None:
     _print_single_run_conclusion_text(
         output_dir=output_dir,
@@ -108,6 +135,7 @@ def _print_single_run_conclusion(
         verbose=verbose,
         figures=figures,
         deep_check=deep_check,
+        throughput_search=throughput_search,
     )
     if output_dir is not None:
         import os
@@ -122,6 +150,7 @@ def _print_single_run_conclusion(
                 verbose=verbose,
                 figures=figures,
                 deep_check=deep_check,
+                throughput_search=throughput_search,
             )
 
 
@@ -132,6 +161,8 @@ def _print_single_run_conclusion_text(
     verbose: bool | int,
     figures: bool,
     deep_check: bool,
+    throughput_search: typing.Mapping[str, flood.ThroughputSearchSummary]
+    | None = None,
 ) -> None:
     import os
     import toolstr
@@ -204,6 +235,11 @@ def _print_single_run_conclusion_text(
     flood.user_io.print_metric_tables(
         results=results, metrics=metrics, indent=4
     )
+    if throughput_search is not None:
+        print()
+        _print_throughput_search_summary(throughput_search, indent=4)
+    _print_not_run_summary(results, indent=4)
+    _print_loader_saturation_summary(results, indent=4)
 
     # deep inspection tables
     if deep_check:
@@ -240,6 +276,55 @@ def _print_single_run_conclusion_text(
             metrics=['n_rpc_errors'],
             indent=4,
         )
+        print()
+        flood.user_io.print_metric_tables(
+            results=deep_results_by_category['failed'],
+            metrics=['n_schema_errors'],
+            indent=4,
+        )
+        failed_results = deep_results_by_category['failed']
+        if any(
+            any(result.get('error_counts', []))
+            for result in failed_results.values()
+        ):
+            print()
+            flood.user_io.print_error_count_tables(failed_results, indent=4)
+
+        # slowest calls of each attack with the params that caused them
+        if any(
+            any(result.get('deep_slowest_calls') or [])
+            for result in results.values()
+        ):
+            print()
+            flood.user_io.print_slowest_calls_tables(results, indent=4)
+
+        # per-call tables for batch requests
+        all_results = deep_results_by_category['all']
+        if any(
+            result['calls'] != result['requests']
+            for result in all_results.values()
+        ):
+            print()
+            flood.user_io.print_metric_tables(
+                results=all_results,
+                metrics=['n_call_errors', 'call_throughput', 'call_p90'],
+                suffix=', per call in batch',
+                indent=4,
+            )
+
+        # latency corrected for coordinated omission, only for open-loop tests
+        if any(
+            value is not None
+            for result in all_results.values()
+            for value in result.get('corrected_p99', [])
+        ):
+            print()
+            flood.user_io.print_metric_tables(
+                results=all_results,
+                metrics=['corrected_p90', 'corrected_p99'],
+                suffix=', corrected for coordinated omission',
+                indent=4,
+            )
 
         metric_names = [
             m for m in metrics if m not in ['success', 'throughput']
@@ -256,3 +341,103 @@ def _print_single_run_conclusion_text(
                 indent=4,
             )
 
+        # per-method tables for mixed workloads
+        deep_results_by_method: typing.MutableMapping[
+            str, typing.MutableMapping[str, flood.LoadTestDeepOutput]
+        ]
+        deep_results_by_method = {}
+        for result_name, result in results.items():
+            deep_method_metrics = result.get('deep_method_metrics')
+            if deep_method_metrics is not None:
+                for method, method_results in deep_method_metrics.items():
+                    deep_results_by_method.setdefault(method, {})
+                    deep_results_by_method[method][
+                        result_name
+                    ] = method_results
+        for method, method_results in deep_results_by_method.items():
+            print()
+            flood.user_io.print_metric_tables(
+                results=method_results,
+                metrics=['success', 'n_rpc_errors'] + metric_names,
+                suffix=', ' + method + ' calls',
+                indent=4,
+            )
+
+
+def _print_not_run_summary(
+    results: typing.Mapping[str, flood.LoadTestOutput],
+    indent: int | str | None = None,
+) -> None:
+    """list loads that were skipped because a stop condition was met"""
+    import toolstr
+
+    for name, result in results.items():
+        not_run = result.get('not_run')
+        if not_run is None or not any(not_run):
+            continue
+        load_label, loads = flood.user_io.outputs._get_result_loads(result)
+        skipped = [
+            str(load) for load, skipped in zip(loads, not_run) if skipped
+        ]
+        print()
+        toolstr.print_bullet(
+            key=name + ' not run after stop condition',
+            value=load_label + ' = ' + ', '.join(skipped),
+            styles=flood.user_io.styles,
+            indent=indent,
+        )
+
+
+def _print_loader_saturation_summary(
+    results: typing.Mapping[str, flood.LoadTestOutput],
+    indent: int | str | None = None,
+) -> None:
+    """warn about loads at which the loader itself was saturated"""
+    import toolstr
+
+    load_tests = flood.tests.load_tests
+    for name, result in results.items():
+        loader_metrics = result.get('loader_metrics')
+        if loader_metrics is None:
+            continue
+        load_label, loads = flood.user_io.outputs._get_result_loads(result)
+        for load, metrics in zip(loads, loader_metrics):
+            reasons = load_tests.get_loader_saturation_reasons(metrics)
+            if len(reasons) == 0:
+                continue
+            print()
+            toolstr.print_bullet(
+                key='WARNING ' + name + ' loader saturated at ' + load_label,
+                value=str(load) + ', ' + ', '.join(reasons),
+                styles=flood.user_io.styles,
+                indent=indent,
+            )
+
+
+def _print_throughput_search_summary(
+    throughput_search: typing.Mapping[str, flood.ThroughputSearchSummary],
+    indent: int | str | None = None,
+) -> None:
+    import toolstr
+
+    styles = flood.user_io.styles
+
+    rows = []
+    for name, summary in throughput_search.items():
+        rows.append(
+            [
+                name,
+                summary['max_sustainable_rate'],
+                len(summary['tested_rates']),
+            ]
+        )
+    toolstr.print_text_box(
+        toolstr.add_style('max sustainable rate', styles.get('metavar')),
+        style=styles.get('content'),
+        indent=indent,
+    )
+    flood.user_io.print_table(
+        rows,
+        labels=['node', 'max rate (rps)', 'samples'],
+        indent=indent,
+    )
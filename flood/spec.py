@@ -20,6 +20,8 @@ if typing.TYPE_CHECKING:
         remote: str | None
         client_version: str | None
         network: str | int | None
+        # prometheus endpoint scraped during attacks
+        metrics_url: str | None
 
     NodeShorthand = typing.Union[str, Node]
 
@@ -80,6 +82,11 @@ if typing.TYPE_CHECKING:
         duration: int
         calls: typing.Sequence[typing.Any]
         vegeta_args: VegetaArgs
+        # closed-loop attacks have a fixed concurrency instead of a rate
+        concurrency: int | None
+        think_time: float | None
+        # replayed attacks send each call at an offset in seconds from start
+        offsets: typing.Sequence[float] | None
 
     VegetaArgs = typing.Union[str, None]
     MultiVegetaArgs = typing.Sequence[VegetaArgs]
@@ -93,6 +100,19 @@ if typing.TYPE_CHECKING:
         durations: typing.Sequence[int] | None
         vegeta_args: VegetaArgsShorthand | None
         network: str
+        batch_size: int | None
+        mode: LoadTestMode | None
+        think_time: float | None
+        # relative share of calls per RPC method, for mixed workloads
+        weights: typing.Mapping[str, float] | None
+        # traffic log to replay instead of generating calls
+        replay_path: str | None
+        replay_speed: float | None
+        # distributed tests generate a share of the test for each loader
+        loader_index: int | None
+        n_loaders: int | None
+        # blocks selected relative to the chain head of each node
+        blocks: BlockSelection | None
 
     # LoadTest = typing.Sequence[VegetaAttack]
     class LoadTest(typing.TypedDict):
@@ -104,8 +124,25 @@ if typing.TYPE_CHECKING:
         durations: typing.Sequence[int]
         calls: typing.Sequence[typing.Sequence[typing.Any]]
         vegeta_args: typing.Sequence[typing.Any]
+        concurrencies: typing.Sequence[int | None]
 
-    LoadTestMode = typing.Literal['stress', 'spike', 'soak']
+    LoadTestMode = typing.Literal[
+        'stress', 'spike', 'soak', 'concurrency', 'search'
+    ]
+
+    LoadEngine = typing.Literal['vegeta', 'native']
+
+    class NetworkDefaults(typing.TypedDict):
+        chain_id: int
+        # historical (start_block, end_block) ranges of each kind of query
+        block_ranges: typing.Mapping[str, tuple[int, int]]
+        # token contracts by symbol
+        contracts: typing.Mapping[str, str]
+
+    # block tag, or number of recent blocks behind the chain head
+    BlockSelection = typing.Union[
+        typing.Literal['latest', 'safe', 'finalized'], int
+    ]
 
     LoadTestGenerator = typing.Callable[..., typing.Sequence[VegetaAttack]]
     MultiLoadTestGenerator = typing.Callable[..., typing.Mapping[str, LoadTest]]
@@ -135,7 +172,8 @@ if typing.TYPE_CHECKING:
         final_wait_time: int
 
     class LoadTestOutputDatum(typing.TypedDict):
-        target_rate: int
+        target_rate: int | None
+        target_concurrency: int | None
         actual_rate: float | None
         target_duration: int
         actual_duration: float | None
@@ -155,18 +193,50 @@ if typing.TYPE_CHECKING:
         last_request_timestamp: str | None
         last_response_timestamp: str | None
         final_wait_time: float | None
+        latency_histogram: LatencyHistogram | None
+        # longest run of timed out requests, ordered by send time, only
+        # counted when a max_consecutive_timeouts stop condition is used
+        max_consecutive_timeouts: int | None
+        # attacks skipped after a stop condition was met have no metrics
+        not_run: bool
+        loader_metrics: LoaderMetrics | None
+        node_metrics: NodeMetricsTimeSeries | None
+        host_metrics: HostMetricsTimeSeries | None
         # additional deep keys
         deep_raw_output: str | None
         deep_metrics: typing.Mapping[
             ResponseCategory, LoadTestDeepOutputDatum
         ] | None
         deep_rpc_error_pairs: typing.Sequence[ErrorPair] | None
+        # deep metrics per RPC method, only for workloads that mix methods
+        deep_method_metrics: typing.Mapping[
+            str, LoadTestDeepOutputDatum
+        ] | None
+        deep_time_series: LoadTestTimeSeries | None
+        deep_slowest_calls: typing.Sequence[SlowCall] | None
+        deep_latency_breakdowns: typing.Mapping[str, LatencyBreakdown] | None
 
     ResponseCategory = typing.Literal['all', 'successful', 'failed']
     ErrorPair = tuple[typing.Any, typing.Any]
 
+    class SlowCall(typing.TypedDict):
+        call: typing.Any
+        latency: float
+        status_code: int
+        success: bool
+
+    class LatencyBreakdown(typing.TypedDict):
+        bin_lower: typing.Sequence[float]
+        bin_upper: typing.Sequence[float]
+        requests: typing.Sequence[int]
+        success: typing.Sequence[float | None]
+        p50: typing.Sequence[float | None]
+        p90: typing.Sequence[float | None]
+        p99: typing.Sequence[float | None]
+
     class LoadTestDeepOutputDatum(typing.TypedDict):
-        target_rate: int
+        target_rate: int | None
+        target_concurrency: int | None
         actual_rate: float | None
         target_duration: int
         actual_duration: float | None
@@ -186,12 +256,33 @@ if typing.TYPE_CHECKING:
         last_request_timestamp: str | None
         last_response_timestamp: str | None
         final_wait_time: float | None
+        latency_histogram: LatencyHistogram | None
         # additional deep keys:
         n_invalid_json_errors: int
         n_rpc_errors: int
+        n_schema_errors: int
+        # failed calls per json-rpc error code and message or failure type
+        error_counts: typing.Mapping[str, int]
+        # per-call keys, differ from per-request keys for batch requests
+        calls: int
+        n_call_errors: int
+        call_throughput: float | None
+        call_mean: float | None
+        call_p50: float | None
+        call_p90: float | None
+        call_p99: float | None
+        # latencies measured from when each request was scheduled to be sent
+        # instead of when it was sent, corrects for coordinated omission
+        corrected_mean: float | None
+        corrected_p50: float | None
+        corrected_p90: float | None
+        corrected_p95: float | None
+        corrected_p99: float | None
+        corrected_max: float | None
 
     class LoadTestOutput(typing.TypedDict):
-        target_rate: typing.Sequence[int]
+        target_rate: typing.Sequence[int | None]
+        target_concurrency: typing.Sequence[int | None]
         actual_rate: typing.Sequence[float | None]
         target_duration: typing.Sequence[int]
         actual_duration: typing.Sequence[float | None]
@@ -211,6 +302,12 @@ if typing.TYPE_CHECKING:
         last_request_timestamp: typing.Sequence[str | None]
         last_response_timestamp: typing.Sequence[str | None]
         final_wait_time: typing.Sequence[float | None]
+        latency_histogram: typing.Sequence[LatencyHistogram | None]
+        max_consecutive_timeouts: typing.Sequence[int | None]
+        not_run: typing.Sequence[bool]
+        loader_metrics: typing.Sequence[LoaderMetrics | None]
+        node_metrics: typing.Sequence[NodeMetricsTimeSeries | None]
+        host_metrics: typing.Sequence[HostMetricsTimeSeries | None]
         # additional deep keys
         deep_raw_output: typing.Sequence[str | None] | None
         deep_metrics: typing.Mapping[
@@ -219,9 +316,18 @@ if typing.TYPE_CHECKING:
         deep_rpc_error_pairs: typing.Sequence[
             typing.Sequence[ErrorPair] | None
         ] | None
+        deep_method_metrics: typing.Mapping[str, LoadTestDeepOutput] | None
+        deep_time_series: typing.Sequence[LoadTestTimeSeries | None] | None
+        deep_slowest_calls: typing.Sequence[
+            typing.Sequence[SlowCall] | None
+        ] | None
+        deep_latency_breakdowns: typing.Sequence[
+            typing.Mapping[str, LatencyBreakdown] | None
+        ] | None
 
     class LoadTestDeepOutput(typing.TypedDict):
-        target_rate: typing.Sequence[int]
+        target_rate: typing.Sequence[int | None]
+        target_concurrency: typing.Sequence[int | None]
         actual_rate: typing.Sequence[float | None]
         target_duration: typing.Sequence[int]
         actual_duration: typing.Sequence[float | None]
@@ -241,9 +347,110 @@ if typing.TYPE_CHECKING:
         last_request_timestamp: typing.Sequence[str | None]
         last_response_timestamp: typing.Sequence[str | None]
         final_wait_time: typing.Sequence[float | None]
+        latency_histogram: typing.Sequence[LatencyHistogram | None]
         # additional deep keys:
         n_invalid_json_errors: typing.Sequence[int]
         n_rpc_errors: typing.Sequence[int]
+        n_schema_errors: typing.Sequence[int]
+        error_counts: typing.Sequence[typing.Mapping[str, int]]
+        # per-call keys, differ from per-request keys for batch requests
+        calls: typing.Sequence[int]
+        n_call_errors: typing.Sequence[int]
+        call_throughput: typing.Sequence[float | None]
+        call_mean: typing.Sequence[float | None]
+        call_p50: typing.Sequence[float | None]
+        call_p90: typing.Sequence[float | None]
+        call_p99: typing.Sequence[float | None]
+        corrected_mean: typing.Sequence[float | None]
+        corrected_p50: typing.Sequence[float | None]
+        corrected_p90: typing.Sequence[float | None]
+        corrected_p95: typing.Sequence[float | None]
+        corrected_p99: typing.Sequence[float | None]
+        corrected_max: typing.Sequence[float | None]
+
+    class LatencyHistogram(typing.TypedDict):
+        # number of log-uniform buckets per doubling of latency
+        sub_buckets: int
+        # seconds, bucket 0 holds all latencies below min_latency
+        min_latency: float
+        # indices of non-empty buckets, sorted
+        buckets: typing.Sequence[int]
+        counts: typing.Sequence[int]
+
+    class LoaderMetrics(typing.TypedDict):
+        # resource usage of load-generating host, sampled during attack
+        interval: float
+        n_samples: int
+        # fraction of all cpus that were busy
+        cpu_mean: float | None
+        cpu_max: float | None
+        # fraction of memory in use
+        memory_max: float | None
+        sockets_max: int | None
+        # seconds that sampling thread woke up late
+        scheduling_lag_mean: float | None
+        scheduling_lag_max: float | None
+
+    class NodeMetricsTimeSeries(typing.TypedDict):
+        # seconds between scrapes of node's prometheus endpoint
+        interval: float
+        # time of each scrape, in seconds since start of attack
+        time: typing.Sequence[float]
+        # value of each series in each scrape, None if missing from scrape
+        series: typing.Mapping[str, typing.Sequence[float | None]]
+        # names of series in each category, e.g. cpu, memory, or db_reads
+        categories: typing.Mapping[str, typing.Sequence[str]]
+
+    class HostMetricsTimeSeries(typing.TypedDict):
+        # system metrics of the host of a remote node, sampled over ssh
+        interval: float
+        # start of each interval, in seconds since start of attack
+        time: typing.Sequence[float]
+        # fraction of all cpus that were busy
+        cpu: typing.Sequence[float | None]
+        # fraction of memory in use
+        memory: typing.Sequence[float]
+        # bytes per second
+        disk_read: typing.Sequence[float]
+        disk_write: typing.Sequence[float]
+        network_receive: typing.Sequence[float]
+        network_transmit: typing.Sequence[float]
+
+    class LoadTestTimeSeries(typing.TypedDict):
+        # seconds per interval
+        interval: float
+        # start of each interval, in seconds since first request of attack
+        time: typing.Sequence[float]
+        requests: typing.Sequence[int]
+        success: typing.Sequence[float | None]
+        throughput: typing.Sequence[float]
+        mean: typing.Sequence[float | None]
+        p50: typing.Sequence[float | None]
+        p90: typing.Sequence[float | None]
+        p99: typing.Sequence[float | None]
+        max: typing.Sequence[float | None]
+
+    class ThroughputSLO(typing.TypedDict):
+        max_p99: float
+        min_success: float
+
+    class StopConditions(typing.TypedDict):
+        # each condition is checked after every attack, None disables it
+        min_success: float | None
+        max_p99: float | None
+        max_consecutive_timeouts: int | None
+
+    class ThroughputSearchSummary(typing.TypedDict):
+        slo: ThroughputSLO
+        max_sustainable_rate: int | None
+        # rates in the order that they were tested
+        tested_rates: typing.Sequence[int]
+        passed: typing.Sequence[bool]
+
+    class ThroughputSearchOutput(typing.TypedDict):
+        summary: ThroughputSearchSummary
+        # samples sorted by rate
+        results: LoadTestOutput
 
     RunType = typing.Literal['single_test']  # noqa: F821
     DeepOutput = typing.Literal['raw', 'metrics']
@@ -263,6 +470,7 @@ if typing.TYPE_CHECKING:
         t_run_end: float
         nodes: Nodes
         results: typing.Mapping[str, LoadTestOutput]
+        throughput_search: typing.Mapping[str, ThroughputSearchSummary] | None
 
     # runner outputs
 
@@ -26,7 +26,11 @@ def run_equality_test(
     if len(nodes) != 2:
         raise Exception('should use two nodes in equality test')
 
-    equality_tests = equality_test_sets.get_all_equality_tests()
+    equality_tests = equality_test_sets.get_all_equality_tests(
+        random_seed=random_seed,
+        network=flood.user_io.parse_nodes_network(nodes),
+        sample_block_hash=test_name in ['all', 'eth_getBlockByHash'],
+    )
 
     # get tests
     if test_name != 'all':
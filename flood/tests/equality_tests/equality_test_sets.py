@@ -10,6 +10,8 @@ def get_all_equality_tests(
     end_block: int = 16_000_000,
     range_size: int = 100,
     random_seed: flood.RandomSeed | None = None,
+    network: str | None = None,
+    sample_block_hash: bool = False,
 ) -> typing.Sequence[flood.EqualityTest]:
     return list(
         get_vanilla_equality_tests(
@@ -17,6 +19,8 @@ def get_all_equality_tests(
             end_block=end_block,
             range_size=range_size,
             random_seed=random_seed,
+            network=network,
+            sample_block_hash=sample_block_hash,
         )
     ) + list(
         get_trace_equality_tests(
@@ -32,7 +36,15 @@ def get_vanilla_equality_tests(
     end_block: int = 16_000_000,
     range_size: int = 100,
     random_seed: flood.RandomSeed | None = None,
+    network: str | None = None,
+    sample_block_hash: bool = False,
 ) -> typing.Sequence[flood.EqualityTest]:
+    """get equality tests of standard methods
+
+    if sample_block_hash, eth_getBlockByHash uses a block hash sampled from
+    the blocks samples of network, otherwise it uses a fixed ethereum block
+    hash and is omitted for other networks
+    """
     import ctc.rpc
 
     start_block, end_block = flood.generators.generate_block_ranges(
@@ -43,7 +55,21 @@ def get_vanilla_equality_tests(
         random_seed=random_seed,
     )[0]
 
-    return [
+    block_hash: str | None
+    if sample_block_hash:
+        if network is None:
+            raise Exception('must specify network to sample block hash')
+        block_hash = flood.generators.generate_block_hashes(
+            n=1,
+            network=network,
+            random_seed=random_seed,
+        )[0]
+    elif network in [None, 'ethereum']:
+        block_hash = '0x3dc4ef568ae2635db1419c5fec55c4a9322c05302ae527cd40bff380c1d465dd'  # noqa: E501
+    else:
+        block_hash = None
+
+    tests: list[flood.EqualityTest] = [
         (
             'eth_getChainId',
             ctc.rpc.construct_eth_chain_id,
@@ -108,9 +134,7 @@ def get_vanilla_equality_tests(
         (
             'eth_getBlockByHash',
             ctc.rpc.construct_eth_get_block_by_hash,
-            [
-                '0x3dc4ef568ae2635db1419c5fec55c4a9322c05302ae527cd40bff380c1d465dd'  # noqa: E501
-            ],
+            [block_hash],
             {},
         ),
         (
@@ -172,6 +196,9 @@ def get_vanilla_equality_tests(
             {},
         ),
     ]
+    if block_hash is None:
+        tests = [test for test in tests if test[0] != 'eth_getBlockByHash']
+    return tests
 
 
 def get_trace_equality_tests(
@@ -1,6 +1,16 @@
 from .deep_utils import *
+from .distributed_load_tests import *
+from .host_monitoring import *
+from .latency_breakdowns import *
+from .latency_histograms import *
 from .load_test_construction import *
 from .load_test_plots import *
 from .load_test_reports import *
 from .load_test_runs import *
+from .loader_monitoring import *
+from .native import *
+from .prometheus import *
+from .raw_outputs import *
+from .rpc_schemas import *
+from .throughput_search import *
 from .vegeta import *
@@ -2,20 +2,47 @@ from __future__ import annotations
 
 import typing
 from ... import spec
+from . import latency_breakdowns
+from . import latency_histograms
+from . import rpc_schemas
 
 if typing.TYPE_CHECKING:
     import polars as pl
 
 
+# number of slowest responses of each attack that are paired with their calls
+default_n_slowest_calls = 10
+
+
 def compute_deep_datum(
     raw_output: bytes,
     target_rate: int,
     target_duration: int,
     calls: typing.Sequence[typing.Any],
+    target_concurrency: int | None = None,
+    offsets: typing.Sequence[float] | None = None,
+    head_block: int | None = None,
 ) -> tuple[
     typing.Mapping[spec.ResponseCategory, spec.LoadTestDeepOutputDatum],
     typing.Sequence[spec.ErrorPair],
+    typing.Mapping[str, spec.LoadTestDeepOutputDatum] | None,
+    spec.LoadTestTimeSeries,
+    typing.Sequence[spec.SlowCall],
+    typing.Mapping[str, spec.LatencyBreakdown],
 ]:
+    """compute deep metrics per response category
+
+    if calls use multiple RPC methods, also compute deep metrics per method
+
+    also computes a time series of metrics over each interval of the attack
+
+    failed and slowest responses are paired with the calls that caused them,
+    and latency is broken down by block number, range size, and response size,
+    and by block age if head_block of the node is given
+
+    offsets are the scheduled send times of replayed attacks, used to correct
+    latencies for coordinated omission
+    """
     import polars as pl
 
     # convert to dataframe
@@ -23,42 +50,101 @@ def compute_deep_datum(
 
     # add error columns
     rpc_error = []
+    schema_error = []
     invalid_json_error = []
-    for status_code, response in zip(all_df['status_code'], all_df['response']):
-        if status_code == 200:
-            try:
-                import json
-                import base64
+    n_calls = []
+    n_call_errors = []
+    rpc_methods = []
+    error_keys = []
+    response_calls = []
+    decoded_responses, call_positions = _decode_and_pair_responses(
+        all_df, calls
+    )
+    for status_code, error, decoded, call_position in zip(
+        all_df['status_code'],
+        all_df['error'],
+        decoded_responses,
+        call_positions,
+    ):
+        invalid_json = decoded is _invalid_json
 
-                decoded = json.loads(base64.b64decode(response))
+        # batch requests are sent as lists of calls
+        call = _get_position_call(call_position, calls)
+        response_calls.append(call)
+        if isinstance(call, list):
+            request_n_calls = len(call)
+        else:
+            request_n_calls = 1
+        n_calls.append(request_n_calls)
+        rpc_methods.append(_get_request_rpc_method(call))
 
-                invalid_json_error.append(False)
-                if decoded.get('result') is None:
-                    rpc_error.append(True)
+        if status_code == 200 and not invalid_json:
+            try:
+                if isinstance(call, list):
+                    n_errors, n_schema_errors = _count_batch_errors(
+                        decoded, call
+                    )
                 else:
-                    rpc_error.append(False)
-
+                    n_errors, n_schema_errors = _count_response_errors(
+                        decoded, _get_request_rpc_method(call)
+                    )
             except Exception:
-                invalid_json_error.append(True)
-                rpc_error.append(False)
+                invalid_json = True
+
+        if status_code == 200 and not invalid_json:
+            invalid_json_error.append(False)
+            rpc_error.append(n_errors > 0)
+            schema_error.append(n_schema_errors > 0)
+            n_call_errors.append(n_errors + n_schema_errors)
+            error_keys.append(
+                _get_response_error_keys(
+                    decoded,
+                    n_errors=n_errors,
+                    n_schema_errors=n_schema_errors,
+                )
+            )
+        elif status_code == 200:
+            invalid_json_error.append(True)
+            rpc_error.append(False)
+            schema_error.append(False)
+            n_call_errors.append(0)
+            error_keys.append(['invalid json'])
         else:
             invalid_json_error.append(False)
             rpc_error.append(False)
+            schema_error.append(False)
+            n_call_errors.append(0)
+            error_keys.append([_get_transport_error_key(status_code, error)])
     all_df = all_df.with_columns(
         pl.Series('invalid_json_error', invalid_json_error),
         pl.Series('rpc_error', rpc_error),
+        pl.Series('schema_error', schema_error),
+        pl.Series('n_calls', n_calls, dtype=pl.Int64),
+        pl.Series('n_call_errors', n_call_errors, dtype=pl.Int64),
+        pl.Series('rpc_method', rpc_methods, dtype=pl.Utf8),
+        pl.Series('error_keys', error_keys, dtype=pl.List(pl.Utf8)),
+        pl.Series('call_position', call_positions, dtype=pl.Int64),
     )
     all_df = all_df.with_columns(
         (
             (pl.col('status_code') == 200)
             & ~pl.col('invalid_json_error')
             & ~pl.col('rpc_error')
+            & ~pl.col('schema_error')
         ).alias('deep_success')
     )
 
+    all_df = _add_corrected_latency(
+        all_df,
+        target_rate=target_rate,
+        target_concurrency=target_concurrency,
+        offsets=offsets,
+    )
+
     # get error pairs
     rpc_error_pairs: typing.Sequence[spec.ErrorPair] = []
     rpc_error_pairs = _gather_error_pairs(df=all_df, calls=calls)
+    slowest_calls = _gather_slowest_calls(df=all_df, calls=calls)
 
     # compute sample metrics
     category_data = {}
@@ -69,10 +155,321 @@ def compute_deep_datum(
     ]
     for category, df in dataframes:
         category_data[category] = _compute_raw_output_sample_metrics(
-            df=df, target_rate=target_rate, target_duration=target_duration
+            df=df,
+            target_rate=target_rate,
+            target_duration=target_duration,
+            target_concurrency=target_concurrency,
         )
 
-    return category_data, rpc_error_pairs
+    # compute per-method metrics for workloads that mix methods
+    method_data = None
+    methods = get_call_rpc_methods(calls)
+    if len(methods) > 1:
+        method_data = {}
+        for method in methods:
+            method_data[method] = _compute_raw_output_sample_metrics(
+                df=all_df.filter(pl.col('rpc_method') == method),
+                target_rate=target_rate,
+                target_duration=target_duration,
+                target_concurrency=target_concurrency,
+            )
+
+    time_series = compute_time_series(all_df)
+    breakdowns = latency_breakdowns.compute_latency_breakdowns(
+        all_df, response_calls, head_block=head_block
+    )
+
+    return (
+        category_data,
+        rpc_error_pairs,
+        method_data,
+        time_series,
+        slowest_calls,
+        breakdowns,
+    )
+
+
+def compute_time_series(
+    df: pl.DataFrame,
+    interval: float = 1.0,
+) -> spec.LoadTestTimeSeries:
+    """compute metrics over each interval of an attack
+
+    requests, success, and latencies are grouped by when requests were sent,
+    throughput is grouped by when successful responses were received
+    """
+    import numpy as np
+
+    if len(df) == 0:
+        return {
+            'interval': interval,
+            'time': [],
+            'requests': [],
+            'success': [],
+            'throughput': [],
+            'mean': [],
+            'p50': [],
+            'p90': [],
+            'p99': [],
+            'max': [],
+        }
+
+    if 'deep_success' in df.columns:
+        successful = df['deep_success'].to_numpy().astype(bool)
+    else:
+        successful = (df['status_code'] == 200).to_numpy()
+    timestamps = df['timestamp'].to_numpy()
+    latencies = df['latency'].to_numpy() / 1e9
+    interval_ns = int(interval * 1e9)
+    t_start = timestamps.min()
+    sent = (timestamps - t_start) // interval_ns
+    received = (timestamps + df['latency'].to_numpy() - t_start) // interval_ns
+    n_intervals = int(max(sent.max(), received.max())) + 1
+
+    requests = np.bincount(sent, minlength=n_intervals)
+    successes = np.bincount(sent[successful], minlength=n_intervals)
+    responses = np.bincount(received[successful], minlength=n_intervals)
+
+    # latencies are sorted by interval so that each interval is contiguous
+    order = np.argsort(sent, kind='stable')
+    bounds = np.concatenate([[0], np.cumsum(requests)])
+    sorted_latencies = latencies[order]
+    latency_stats: dict[str, list[float | None]] = {
+        'mean': [],
+        'p50': [],
+        'p90': [],
+        'p99': [],
+        'max': [],
+    }
+    for i in range(n_intervals):
+        interval_latencies = sorted_latencies[bounds[i] : bounds[i + 1]]
+        if len(interval_latencies) == 0:
+            for values in latency_stats.values():
+                values.append(None)
+            continue
+        p50, p90, p99 = np.percentile(interval_latencies, [50, 90, 99])
+        latency_stats['mean'].append(float(interval_latencies.mean()))
+        latency_stats['p50'].append(float(p50))
+        latency_stats['p90'].append(float(p90))
+        latency_stats['p99'].append(float(p99))
+        latency_stats['max'].append(float(interval_latencies.max()))
+
+    return {
+        'interval': interval,
+        'time': [i * interval for i in range(n_intervals)],
+        'requests': requests.tolist(),
+        'success': [
+            float(n_success / n_requests) if n_requests > 0 else None
+            for n_success, n_requests in zip(successes, requests)
+        ],
+        'throughput': (responses / interval).tolist(),
+        'mean': latency_stats['mean'],
+        'p50': latency_stats['p50'],
+        'p90': latency_stats['p90'],
+        'p99': latency_stats['p99'],
+        'max': latency_stats['max'],
+    }
+
+
+def _add_corrected_latency(
+    df: pl.DataFrame,
+    *,
+    target_rate: int,
+    target_concurrency: int | None,
+    offsets: typing.Sequence[float] | None,
+) -> pl.DataFrame:
+    """add latency measured from when each request was scheduled to be sent
+
+    when a node stalls, requests are sent later than scheduled and their wait
+    is missing from the measured latency (coordinated omission), closed-loop
+    attacks have no schedule so their corrected latency is null
+    """
+    import numpy as np
+    import polars as pl
+
+    if len(df) == 0 or target_concurrency is not None or (
+        offsets is None and not target_rate
+    ):
+        return df.with_columns(
+            pl.lit(None, dtype=pl.Int64).alias('corrected_latency')
+        )
+
+    # scheduled send time of each request relative to start of schedule
+    index = df['index'].to_numpy()
+    if offsets is not None:
+        schedule = (np.asarray(offsets) * 1e9).astype(np.int64)
+        scheduled = schedule[index % len(schedule)]
+    else:
+        scheduled = (index * (1e9 / target_rate)).astype(np.int64)
+
+    # anchor schedule so that no request was sent earlier than scheduled
+    timestamps = df['timestamp'].to_numpy()
+    send_delay = timestamps - scheduled
+    send_delay -= send_delay.min()
+    corrected = df['latency'].to_numpy() + send_delay
+    return df.with_columns(
+        pl.Series('corrected_latency', corrected, dtype=pl.Int64)
+    )
+
+
+def get_call_rpc_methods(
+    calls: typing.Sequence[typing.Any],
+) -> typing.Sequence[str]:
+    """get sorted RPC methods of calls, batches of mixed methods are 'batch'"""
+    methods = set()
+    for call in calls:
+        method = _get_request_rpc_method(call)
+        if method is not None:
+            methods.add(method)
+    return sorted(methods)
+
+
+def _get_request_rpc_method(call: typing.Any) -> str | None:
+    if isinstance(call, list):
+        methods = {item.get('method') for item in call}
+        if len(methods) == 1:
+            return methods.pop()  # type: ignore
+        else:
+            return 'batch'
+    elif call is not None:
+        return call.get('method')  # type: ignore
+    else:
+        return None
+
+
+def _get_response_error_keys(
+    decoded: typing.Any, *, n_errors: int, n_schema_errors: int
+) -> typing.Sequence[str]:
+    """get error key of each failed call in response
+
+    json-rpc errors are keyed by code and normalized message, failed calls
+    without a json-rpc error are keyed by how they failed
+    """
+    if isinstance(decoded, list):
+        items = decoded
+    else:
+        items = [decoded]
+    keys = []
+    for item in items:
+        if isinstance(item, dict) and isinstance(item.get('error'), dict):
+            keys.append(get_rpc_error_key(item['error']))
+    keys = keys[:n_errors]
+    keys.extend(['null or missing result'] * (n_errors - len(keys)))
+    keys.extend(['invalid result schema'] * n_schema_errors)
+    return keys
+
+
+def get_rpc_error_key(error: typing.Mapping[str, typing.Any]) -> str:
+    """get key of json-rpc error from its code and normalized message"""
+    code = error.get('code')
+    message = error.get('message')
+    if not isinstance(message, str):
+        message = ''
+    key = normalize_error_message(message)
+    if code is not None:
+        key = (str(code) + ' ' + key).strip()
+    return key
+
+
+def normalize_error_message(message: str, max_length: int = 80) -> str:
+    """normalize message so that errors differing only by values match
+
+    e.g. hashes, addresses, and numbers are replaced by placeholders
+    """
+    import re
+
+    message = message.lower()
+    message = re.sub(r'0x[0-9a-f]+', '0x...', message)
+    message = re.sub(r'\b[0-9a-f]{16,}\b', '0x...', message)
+    message = re.sub(r'\b[0-9]+\b', 'N', message)
+    message = ' '.join(message.split())
+    if len(message) > max_length:
+        message = message[: max_length - 3] + '...'
+    return message
+
+
+def _get_transport_error_key(status_code: int, error: str | None) -> str:
+    """get key of request that failed without a status 200 response"""
+    from . import vegeta
+
+    if vegeta._is_timeout_error(error):
+        return 'timeout'
+    elif status_code != 0 and status_code is not None:
+        return 'http ' + str(status_code)
+    elif error is not None:
+        # drop request prefix, e.g. 'Post "http://localhost:8545": '
+        message = error.rsplit('": ', 1)[-1]
+        return normalize_error_message(message)
+    else:
+        return 'unknown error'
+
+
+def _count_response_errors(
+    decoded: typing.Any, method: str | None
+) -> tuple[int, int]:
+    """count rpc errors and schema violations of a single call's response
+
+    results are validated against the schema of their method, so legitimate
+    nulls such as receipts of pending transactions are not rpc errors, null
+    results of methods without a schema are still counted as rpc errors
+    """
+    if not isinstance(decoded, dict):
+        raise Exception('response is not a json object')
+    if 'error' in decoded or 'result' not in decoded:
+        return 1, 0
+    schema = rpc_schemas.get_rpc_result_schema(method)
+    if schema is None:
+        if decoded['result'] is None:
+            return 1, 0
+        else:
+            return 0, 0
+    elif rpc_schemas.is_valid_rpc_result(decoded['result'], schema):
+        return 0, 0
+    else:
+        return 0, 1
+
+
+def _count_batch_errors(
+    decoded: typing.Any, calls: typing.Sequence[typing.Any]
+) -> tuple[int, int]:
+    """count calls of a batch that are missing, errored, or malformed"""
+    n_calls = len(calls)
+    if not isinstance(decoded, list):
+        return n_calls, 0
+    n_errors = max(n_calls - len(decoded), 0)
+    n_schema_errors = 0
+    for position, item in enumerate(decoded):
+        if not isinstance(item, dict):
+            n_errors += 1
+            continue
+        position = _get_batch_item_position(item.get('id'), position, calls)
+        if position < n_calls:
+            method = calls[position].get('method')
+        else:
+            method = None
+        item_errors, item_schema_errors = _count_response_errors(item, method)
+        n_errors += item_errors
+        n_schema_errors += item_schema_errors
+    n_errors = min(n_errors, n_calls)
+    return n_errors, min(n_schema_errors, n_calls - n_errors)
+
+
+def _get_batch_item_position(
+    item_id: typing.Any, position: int, calls: typing.Sequence[typing.Any]
+) -> int:
+    """get position of response's call within its batch
+
+    websocket batches have ids '{seq}.{i}' where i is the call's position,
+    http batches keep the ids of their calls, responses may be reordered
+    """
+    if isinstance(item_id, str) and '.' in item_id:
+        index = item_id.rsplit('.', 1)[1]
+        if index.isdigit():
+            return int(index)
+    for index, call in enumerate(calls):
+        if call.get('id') == item_id:
+            return index
+    return position
 
 
 def _convert_raw_vegeta_output_to_dataframe(raw_output: bytes) -> pl.DataFrame:
@@ -81,6 +478,10 @@ def _convert_raw_vegeta_output_to_dataframe(raw_output: bytes) -> pl.DataFrame:
     import subprocess
     import polars as pl
 
+    # native engine output is already json lines, no need for vegeta
+    if raw_output.lstrip()[:1] == b'{':
+        return _convert_raw_native_output_to_dataframe(raw_output)
+
     cmd = 'vegeta encode --to csv'
     report_output = (
         subprocess.check_output(cmd.split(' '), input=raw_output)
@@ -120,7 +521,47 @@ def _convert_raw_vegeta_output_to_dataframe(raw_output: bytes) -> pl.DataFrame:
         'timestamp': pl.Int64,
         'url': pl.Utf8,
     }
-    return pl.read_csv(buf, new_columns=schema, has_header=False, dtypes=dtypes)
+    df = pl.read_csv(buf, new_columns=schema, has_header=False, dtypes=dtypes)
+    return df.with_columns(pl.lit(None, dtype=pl.Utf8).alias('rpc_method'))
+
+
+def _convert_raw_native_output_to_dataframe(raw_output: bytes) -> pl.DataFrame:
+    """convert json lines raw output to the same schema as vegeta csv"""
+    import polars as pl
+    from . import native
+
+    results = native.decode_native_results(raw_output)
+    data = {
+        'timestamp': [result['timestamp'] for result in results],
+        'status_code': [result['code'] for result in results],
+        'latency': [result['latency'] for result in results],
+        'bytes_out': [result['bytes_out'] for result in results],
+        'bytes_in': [result['bytes_in'] for result in results],
+        'error': [result['error'] or None for result in results],
+        'response': [result['body'] for result in results],
+        'name': [result['attack'] for result in results],
+        'index': [result['seq'] for result in results],
+        'method': [result['method'] for result in results],
+        'url': [result['url'] for result in results],
+        'response_headers': [None for result in results],
+        'rpc_method': [result.get('rpc_method') for result in results],
+    }
+    schema = {
+        'timestamp': pl.Int64,
+        'status_code': pl.Int64,
+        'latency': pl.Int64,
+        'bytes_out': pl.Int64,
+        'bytes_in': pl.Int64,
+        'error': pl.Utf8,
+        'response': pl.Utf8,
+        'name': pl.Utf8,
+        'index': pl.Int64,
+        'method': pl.Utf8,
+        'url': pl.Utf8,
+        'response_headers': pl.Utf8,
+        'rpc_method': pl.Utf8,
+    }
+    return pl.DataFrame(data, schema=schema)
 
 
 def _gather_error_pairs(
@@ -128,53 +569,224 @@ def _gather_error_pairs(
 ) -> typing.Sequence[spec.ErrorPair]:
     import polars as pl
 
-    calls_by_id = {}
-    for call in calls:
-        call_id = call.get('id')
-        if call_id is None:
-            raise Exception('id not specified for call')
-        elif call_id in calls_by_id:
-            raise Exception('duplicate call for id')
-        calls_by_id[call_id] = call
-
-    responses = df.filter(pl.col('rpc_error'))['response']
+    errors = df.filter(pl.col('rpc_error') | pl.col('schema_error'))
 
     pairs = []
-    for response in responses:
-        pairs.append((None, response))
+    for call_position, response in zip(
+        errors['call_position'], errors['response']
+    ):
+        call = _get_position_call(call_position, calls)
+        pairs.append((call, _decode_response(response)))
 
     return pairs
 
 
+def _gather_slowest_calls(
+    df: pl.DataFrame,
+    calls: typing.Sequence[typing.Any],
+    n: int | None = None,
+) -> typing.Sequence[spec.SlowCall]:
+    """get the n slowest responses of attack and the calls that caused them"""
+    if n is None:
+        n = default_n_slowest_calls
+
+    slowest = df.sort('latency', descending=True).head(n)
+
+    slowest_calls: list[spec.SlowCall] = []
+    for call_position, latency, status_code, success in zip(
+        slowest['call_position'],
+        slowest['latency'],
+        slowest['status_code'],
+        slowest['deep_success'],
+    ):
+        call = _get_position_call(call_position, calls)
+        slowest_calls.append(
+            {
+                'call': call,
+                'latency': latency / 1e9,
+                'status_code': status_code,
+                'success': success,
+            }
+        )
+    return slowest_calls
+
+
+# placeholder for status 200 responses whose body is not valid json
+_invalid_json = object()
+
+
+def _decode_and_pair_responses(
+    df: pl.DataFrame, calls: typing.Sequence[typing.Any]
+) -> tuple[typing.Sequence[typing.Any], typing.Sequence[int | None]]:
+    """decode body of each response and find position of its call in calls
+
+    bodies of responses without status 200 are None
+    """
+    import base64
+    import json
+
+    decoded_responses = []
+    call_positions = []
+    get_call_position = _get_call_position_lookup(calls)
+    for status_code, response, index, transport in zip(
+        df['status_code'], df['response'], df['index'], df['method']
+    ):
+        decoded = None
+        if status_code == 200:
+            try:
+                decoded = json.loads(base64.b64decode(response))
+            except Exception:
+                decoded = _invalid_json
+        decoded_responses.append(decoded)
+        call_positions.append(
+            _get_response_call_position(
+                index=index,
+                decoded=decoded,
+                transport=transport,
+                n_calls=len(calls),
+                get_call_position=get_call_position,
+            )
+        )
+    return decoded_responses, call_positions
+
+
+def get_response_rpc_methods(
+    df: pl.DataFrame, calls: typing.Sequence[typing.Any]
+) -> typing.Sequence[str | None]:
+    """get RPC method of the call of each response, 'batch' for mixed batches
+
+    df is raw output as converted by _convert_raw_vegeta_output_to_dataframe
+    """
+    _, call_positions = _decode_and_pair_responses(df, calls)
+    return [
+        _get_request_rpc_method(_get_position_call(call_position, calls))
+        for call_position in call_positions
+    ]
+
+
+def _get_position_call(
+    call_position: int | None, calls: typing.Sequence[typing.Any]
+) -> typing.Any:
+    if call_position is None:
+        return None
+    return calls[call_position]
+
+
+def _get_response_call_position(
+    *,
+    index: int,
+    decoded: typing.Any,
+    transport: str | None,
+    n_calls: int,
+    get_call_position: typing.Callable[[typing.Any], int | None],
+) -> int | None:
+    """get position in calls of the call that caused a response
+
+    responses are matched by json-rpc id, since vegeta can assign the seq of
+    a request before taking its target, so seq may not follow target order
+
+    the target index is used if the id is missing or matches no single call,
+    and for websocket requests, whose ids are replaced by their seq
+    """
+    if n_calls == 0:
+        return None
+    if transport != 'WS':
+        if isinstance(decoded, list):
+            items = decoded
+        else:
+            items = [decoded]
+        positions = {
+            get_call_position(item.get('id'))
+            for item in items
+            if isinstance(item, dict)
+        }
+        if len(positions) == 1:
+            position = positions.pop()
+            if position is not None:
+                return position
+    return index % n_calls
+
+
+def _get_call_position_lookup(
+    calls: typing.Sequence[typing.Any],
+) -> typing.Callable[[typing.Any], int | None]:
+    """get function that finds the position of the call with a json-rpc id
+
+    calls of a batch share the batch's position, ids that are missing or that
+    are shared by calls at different positions give None
+    """
+    from ... import generators
+    from . import load_test_construction
+
+    if isinstance(calls, generators.LazyCalls):
+        return calls.get_position
+    if isinstance(calls, load_test_construction.CallBatches):
+        get_call_position = _get_call_position_lookup(calls.calls)
+        batch_size = calls.batch_size
+
+        def get_batch_position(call_id: typing.Any) -> int | None:
+            position = get_call_position(call_id)
+            if position is None:
+                return None
+            return position // batch_size
+
+        return get_batch_position
+
+    positions: dict[typing.Any, int | None] = {}
+    for position, call in enumerate(calls):
+        if isinstance(call, list):
+            items = call
+        else:
+            items = [call]
+        for item in items:
+            call_id = item.get('id')
+            if not isinstance(call_id, (int, str)):
+                continue
+            if positions.get(call_id, position) != position:
+                positions[call_id] = None
+            else:
+                positions[call_id] = position
+
+    def get_position(call_id: typing.Any) -> int | None:
+        if not isinstance(call_id, (int, str)):
+            return None
+        return positions.get(call_id)
+
+    return get_position
+
+
+def _decode_response(response: str | None) -> typing.Any:
+    """decode base64 response body into json, or str if not valid json"""
+    import base64
+    import json
+
+    if response is None:
+        return None
+    body = base64.b64decode(response)
+    try:
+        return json.loads(body)
+    except ValueError:
+        return body.decode(errors='replace')
+
+
 def _compute_raw_output_sample_metrics(
-    df: pl.DataFrame, target_rate: int, target_duration: int
+    df: pl.DataFrame,
+    target_rate: int,
+    target_duration: int,
+    target_concurrency: int | None = None,
 ) -> spec.LoadTestDeepOutputDatum:
     """convert standard test metrics from vegeta raw output dataframe"""
+    if target_concurrency is not None:
+        use_target_rate = None
+    else:
+        use_target_rate = target_rate
+
     if len(df) == 0:
-        return {
-            'target_rate': target_rate,
-            'actual_rate': 0,
-            'target_duration': target_duration,
-            'actual_duration': None,
-            'requests': 0,
-            'throughput': None,
-            'success': None,
-            'min': None,
-            'mean': None,
-            'p50': None,
-            'p90': None,
-            'p95': None,
-            'p99': None,
-            'max': None,
-            'status_codes': {},
-            'errors': [],
-            'first_request_timestamp': None,
-            'last_request_timestamp': None,
-            'last_response_timestamp': None,
-            'final_wait_time': None,
-            'n_invalid_json_errors': 0,
-            'n_rpc_errors': 0,
-        }
+        return _get_empty_sample_metrics(
+            target_rate=use_target_rate,
+            target_duration=target_duration,
+            target_concurrency=target_concurrency,
+        )
 
     import polars as pl
 
@@ -233,12 +845,113 @@ def _compute_raw_output_sample_metrics(
     )
 
     output: spec.LoadTestDeepOutputDatum = metrics_df.to_dicts()[0]  # type: ignore # noqa: E501
+    output['target_rate'] = use_target_rate
+    output['target_concurrency'] = target_concurrency
     output['n_invalid_json_errors'] = int(df['invalid_json_error'].sum())
     output['n_rpc_errors'] = int(df['rpc_error'].sum())
+    output['n_schema_errors'] = int(df['schema_error'].sum())
+    output['error_counts'] = _count_error_keys(df)
+
+    # per-call metrics, latency of a batch is amortized over its calls
+    n_successful_calls = (
+        successful['n_calls'].sum() - successful['n_call_errors'].sum()
+    )
+    call_latency = df['latency'] / df['n_calls'] / 1e9
+    output['calls'] = int(df['n_calls'].sum())
+    output['n_call_errors'] = int(df['n_call_errors'].sum())
+    output['call_throughput'] = n_successful_calls / total_duration * 1e9
+    output['call_mean'] = call_latency.mean()
+    output['call_p50'] = call_latency.quantile(0.50)
+    output['call_p90'] = call_latency.quantile(0.90)
+    output['call_p99'] = call_latency.quantile(0.99)
+
+    # latency corrected for coordinated omission
+    corrected_latency = df['corrected_latency'].drop_nulls() / 1e9
+    if len(corrected_latency) > 0:
+        output['corrected_mean'] = corrected_latency.mean()
+        output['corrected_p50'] = corrected_latency.quantile(0.50)
+        output['corrected_p90'] = corrected_latency.quantile(0.90)
+        output['corrected_p95'] = corrected_latency.quantile(0.95)
+        output['corrected_p99'] = corrected_latency.quantile(0.99)
+        output['corrected_max'] = corrected_latency.max()
+    else:
+        for key in _corrected_latency_keys:
+            output[key] = None  # type: ignore
+
+    output['latency_histogram'] = latency_histograms.create_latency_histogram(
+        (df['latency'] / 1e9).to_numpy()
+    )
 
     return output
 
 
+def _count_error_keys(df: pl.DataFrame) -> typing.Mapping[str, int]:
+    """count failed calls per error key, ordered from most to least common"""
+    counts: dict[str, int] = {}
+    for keys in df['error_keys'].to_list():
+        for key in keys:
+            counts[key] = counts.get(key, 0) + 1
+    return dict(sorted(counts.items(), key=lambda item: -item[1]))
+
+
+def _get_empty_sample_metrics(
+    target_rate: int | None,
+    target_duration: int,
+    target_concurrency: int | None = None,
+) -> spec.LoadTestDeepOutputDatum:
+    return {
+        'target_rate': target_rate,
+        'target_concurrency': target_concurrency,
+        'actual_rate': 0,
+        'target_duration': target_duration,
+        'actual_duration': None,
+        'requests': 0,
+        'throughput': None,
+        'success': None,
+        'min': None,
+        'mean': None,
+        'p50': None,
+        'p90': None,
+        'p95': None,
+        'p99': None,
+        'max': None,
+        'status_codes': {},
+        'errors': [],
+        'first_request_timestamp': None,
+        'last_request_timestamp': None,
+        'last_response_timestamp': None,
+        'final_wait_time': None,
+        'latency_histogram': latency_histograms.create_latency_histogram([]),
+        'n_invalid_json_errors': 0,
+        'n_rpc_errors': 0,
+        'n_schema_errors': 0,
+        'error_counts': {},
+        'calls': 0,
+        'n_call_errors': 0,
+        'call_throughput': None,
+        'call_mean': None,
+        'call_p50': None,
+        'call_p90': None,
+        'call_p99': None,
+        'corrected_mean': None,
+        'corrected_p50': None,
+        'corrected_p90': None,
+        'corrected_p95': None,
+        'corrected_p99': None,
+        'corrected_max': None,
+    }
+
+
+_corrected_latency_keys = [
+    'corrected_mean',
+    'corrected_p50',
+    'corrected_p90',
+    'corrected_p95',
+    'corrected_p99',
+    'corrected_max',
+]
+
+
 # def compute_raw_output_metrics(
 #     raw_output: typing.Mapping[str, pl.DataFrame],
 #     results: typing.Mapping[str, spec.LoadTestOutput],
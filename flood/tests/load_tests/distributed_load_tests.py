@@ -0,0 +1,280 @@
+"""split load tests across multiple remote loader hosts
+
+each loader sends an equal share of every attack's rate and calls, so that
+one node can be tested at rates that a single loader cannot generate
+
+loaders start each attack at the same scheduled time, then the raw outputs
+of all loaders are merged so that percentiles are computed over all requests
+instead of being averaged across loaders
+"""
+from __future__ import annotations
+
+import typing
+
+import flood
+from flood import spec
+from flood import user_io
+from . import loader_monitoring
+from . import native
+from . import vegeta
+
+if typing.TYPE_CHECKING:
+    import multiprocessing
+
+
+# seconds between launching loaders and the first scheduled attack
+distributed_start_delay = 30
+
+# seconds between the end of one scheduled attack and start of the next
+distributed_attack_gap = 15
+
+
+def split_attack(
+    attack: spec.VegetaAttack,
+    *,
+    loader_index: int,
+    n_loaders: int,
+) -> spec.VegetaAttack:
+    """get the share of an attack that is sent by one loader"""
+    if not 0 <= loader_index < n_loaders:
+        raise Exception('loader_index must be between 0 and n_loaders - 1')
+
+    def get_share(total: int) -> int:
+        share = total // n_loaders
+        if loader_index < total % n_loaders:
+            share += 1
+        if share == 0:
+            raise Exception('load too low to split across loaders')
+        return share
+
+    concurrency = attack['concurrency']
+    offsets = attack['offsets']
+    if concurrency is not None:
+        rate = attack['rate']
+        concurrency = get_share(concurrency)
+    else:
+        rate = get_share(attack['rate'])
+    if offsets is not None:
+        offsets = offsets[loader_index::n_loaders]
+
+    return {
+        'rate': rate,
+        'duration': attack['duration'],
+        'calls': attack['calls'][loader_index::n_loaders],
+        'vegeta_args': attack['vegeta_args'],
+        'concurrency': concurrency,
+        'think_time': attack['think_time'],
+        'offsets': offsets,
+    }
+
+
+def get_distributed_attack_start_times(
+    start_time: float,
+    durations: typing.Sequence[int],
+) -> typing.Sequence[float]:
+    """get the time at which every loader starts each attack"""
+    start_times = []
+    t_attack = start_time
+    for duration in durations:
+        start_times.append(t_attack)
+        t_attack += duration + distributed_attack_gap
+    return start_times
+
+
+def run_distributed_load_test(
+    *,
+    node: spec.NodeShorthand,
+    test: spec.LoadTest | spec.TestGenerationParameters,
+    loaders: typing.Sequence[str],
+    verbose: bool | int = False,
+    include_deep_output: typing.Sequence[spec.DeepOutput] | None = None,
+    engine: spec.LoadEngine | None = None,
+) -> spec.LoadTestOutput:
+    """run a load test against a single node from multiple loader hosts"""
+    import json
+    import multiprocessing
+    import time
+
+    from . import load_test_runs
+
+    node = user_io.parse_node(node)
+    if node['remote'] is not None:
+        raise Exception('node of distributed test should not be remote')
+    if len(loaders) == 0:
+        raise Exception('must specify at least one loader')
+
+    # generate test locally, calls are needed to merge deep metrics
+    use_test: spec.LoadTest
+    if 'attacks' in test:
+        use_test = test  # type: ignore
+    else:
+        use_test = flood.generate_test(
+            **test, rpc_url=node['url']  # type: ignore
+        )
+    test_parameters = use_test['test_parameters']
+    if test_parameters.get('n_loaders') is not None:
+        raise Exception('test is already split across loaders')
+
+    if verbose:
+        flood.user_io.print_timestamped(
+            'Running load test for '
+            + node['name']
+            + ' from '
+            + str(len(loaders))
+            + ' loaders'
+        )
+
+    # launch share of test on each loader
+    start_time = time.time() + distributed_start_delay
+    processes: list[
+        tuple[multiprocessing.Process, multiprocessing.Queue[str]]
+    ] = []
+    for loader_index, loader in enumerate(loaders):
+        # only the first loader scrapes the node's metrics
+        if loader_index == 0:
+            metrics_url = node.get('metrics_url')
+        else:
+            metrics_url = None
+        loader_node: spec.Node = dict(  # type: ignore
+            node,
+            name=node['name'] + '__loader' + str(loader_index),
+            remote=loader,
+            metrics_url=metrics_url,
+        )
+        loader_test = dict(
+            test_parameters,
+            loader_index=loader_index,
+            n_loaders=len(loaders),
+        )
+        queue: multiprocessing.Queue[str] = multiprocessing.Queue()
+        process = multiprocessing.Process(
+            target=load_test_runs.run_load_test,
+            kwargs=dict(
+                node=loader_node,
+                test=loader_test,
+                verbose=verbose,
+                include_deep_output=['raw'],
+                engine=engine,
+                start_time=start_time,
+                _container=queue,
+            ),
+        )
+        process.start()
+        processes.append((process, queue))
+
+    # gather raw outputs of each loader
+    loader_raw_outputs = []
+    loader_metrics = []
+    node_metrics = None
+    try:
+        for process, queue in processes:
+            process.join()
+            if process.exitcode != 0:
+                raise Exception('distributed load test failed on loader')
+            with open(queue.get(), 'r') as f:
+                loader_results: spec.SingleRunResultsPayload = json.load(f)
+            loader_output = list(loader_results['results'].values())[0]
+            if loader_output['deep_raw_output'] is None:
+                raise Exception('loader did not return raw output')
+            loader_raw_outputs.append(loader_output['deep_raw_output'])
+            loader_metrics.append(loader_output.get('loader_metrics'))
+            if node_metrics is None:
+                node_metrics = loader_output.get('node_metrics')
+    except BaseException:
+        # stop other loaders from running their scheduled attacks
+        _stop_loader_processes([process for process, queue in processes])
+        raise
+
+    # merge raw outputs of each attack
+    if verbose:
+        flood.user_io.print_timestamped(
+            'Merging results of loaders for ' + node['name']
+        )
+    results = []
+    for a, attack in enumerate(use_test['attacks']):
+        raw_outputs = []
+        for raw_output in loader_raw_outputs:
+            encoded = raw_output[a]
+            if encoded is None:
+                raise Exception('loader did not return raw output')
+            raw_outputs.append(
+                flood.tests.load_tests.decode_raw_vegeta_output(encoded)
+            )
+        merged = merge_loader_raw_outputs(
+            raw_outputs,
+            n_calls=len(attack['calls']),
+        )
+        result = vegeta._create_vegeta_report(
+            attack_output=merged,
+            target_rate=attack['rate'],
+            target_concurrency=attack['concurrency'],
+            target_duration=attack['duration'],
+            include_deep_output=include_deep_output,
+            calls=attack['calls'],
+            engine='native',
+            offsets=attack['offsets'],
+            loader_metrics=loader_monitoring.merge_loader_metrics(
+                [
+                    None if metrics is None else metrics[a]
+                    for metrics in loader_metrics
+                ]
+            ),
+            node_metrics=None if node_metrics is None else node_metrics[a],
+        )
+        results.append(result)
+
+    return load_test_runs._format_load_test_output(
+        results=results, include_deep_output=include_deep_output
+    )
+
+
+def _stop_loader_processes(
+    processes: typing.Sequence[multiprocessing.Process],
+) -> None:
+    """terminate and join loader processes that are still running"""
+    for process in processes:
+        if process.is_alive():
+            process.terminate()
+    for process in processes:
+        process.join()
+
+
+def merge_loader_raw_outputs(
+    raw_outputs: typing.Sequence[bytes],
+    *,
+    n_calls: int,
+) -> bytes:
+    """merge raw outputs of loaders into json lines ordered by send time
+
+    raw outputs are given in loader order, the seq of each result is mapped
+    back to the index of its call in the attack before it was split, plus
+    n_calls for each time the loader has cycled through its calls, so that
+    seqs stay unique and seq % n_calls is the index of the call
+    """
+    import base64
+    import subprocess
+
+    n_loaders = len(raw_outputs)
+    results = []
+    for loader_index, raw_output in enumerate(raw_outputs):
+        n_loader_calls = len(range(loader_index, n_calls, n_loaders))
+        if n_loader_calls == 0:
+            continue
+
+        # vegeta binary output is converted to the native json lines format
+        if raw_output.lstrip()[:1] != b'{':
+            cmd = 'vegeta encode --to json'
+            raw_output = subprocess.check_output(
+                cmd.split(' '), input=raw_output
+            )
+
+        for result in native.decode_native_results(raw_output):
+            result = dict(result)
+            n_cycles, loader_call_index = divmod(result['seq'], n_loader_calls)
+            call_index = loader_index + loader_call_index * n_loaders
+            result['seq'] = call_index + n_cycles * n_calls
+            result['body'] = base64.b64decode(result['body'] or '')
+            results.append(result)
+
+    results.sort(key=lambda result: result['timestamp'])
+    return native._encode_native_results(results)
@@ -0,0 +1,254 @@
+"""sample system metrics of the hosts of remote nodes during attacks
+
+remote nodes are given as user@host:url, so flood already has ssh access to
+the node's host, the same access is used to read /proc of that host while
+the remote test runs
+
+samples are timestamped by the node host's clock and split into attacks
+using the timestamps of each attack in the retrieved results.json
+
+local nodes share the host of the loader, see loader_monitoring for that
+
+metrics are read from /proc, so they are only collected on linux hosts
+"""
+from __future__ import annotations
+
+import typing
+
+from ... import spec
+from . import loader_monitoring
+
+if typing.TYPE_CHECKING:
+    import subprocess
+
+
+default_host_sample_interval = 1.0
+
+# sections of each sample printed by the remote sampling loop
+_sample_marker = '__flood_sample__'
+_section_marker = '__flood_section__'
+
+
+class RemoteHostSampler:
+    """sample cpu, memory, disk io, and network io of a host over ssh
+
+    a single ssh session runs a shell loop on the host that prints /proc
+    counters every interval, they are parsed in a background thread
+    """
+
+    def __init__(self, remote: str, interval: float | None = None) -> None:
+        import threading
+
+        if interval is None:
+            interval = default_host_sample_interval
+        self.remote = remote
+        self.interval = interval
+        self._samples: list[tuple[float, typing.Mapping[str, float]]] = []
+        self._process: subprocess.Popen[str] | None = None
+        self._thread = threading.Thread(target=self._run, daemon=True)
+
+    def start(self) -> None:
+        import subprocess
+
+        script = (
+            'while true; do '
+            + 'echo ' + _sample_marker + ' $(date +%s.%N); '
+            + 'echo ' + _section_marker + ' blocks; ls /sys/block; '
+            + 'for f in stat meminfo diskstats net/dev; do '
+            + 'echo ' + _section_marker + ' $f; cat /proc/$f; '
+            + 'done; '
+            + 'sleep ' + str(self.interval) + '; '
+            + 'done'
+        )
+        self._process = subprocess.Popen(
+            ['ssh', self.remote, script],
+            stdout=subprocess.PIPE,
+            stderr=subprocess.DEVNULL,
+            text=True,
+        )
+        self._thread.start()
+
+    def stop(
+        self,
+    ) -> typing.Sequence[tuple[float, typing.Mapping[str, float]]]:
+        """stop sampling, return timestamped counters of each sample"""
+        if self._process is not None:
+            self._process.terminate()
+            self._process.wait()
+        self._thread.join()
+        return self._samples
+
+    def _run(self) -> None:
+        if self._process is None or self._process.stdout is None:
+            return
+        lines: list[str] = []
+        for line in self._process.stdout:
+            if line.startswith(_sample_marker):
+                self._add_sample(lines)
+                lines = []
+            lines.append(line)
+        # the last sample is dropped, it may be cut off by terminating ssh
+
+    def _add_sample(self, lines: typing.Sequence[str]) -> None:
+        if len(lines) == 0:
+            return
+        try:
+            timestamp = float(lines[0].split()[1])
+            counters = parse_host_counters(lines[1:])
+        except (IndexError, ValueError):
+            return
+        if counters is not None:
+            self._samples.append((timestamp, counters))
+
+
+def parse_host_counters(
+    lines: typing.Sequence[str],
+) -> typing.Mapping[str, float] | None:
+    """parse cumulative cpu, disk, and network counters and memory usage
+
+    lines are sections of /proc files, each after a line with its name
+    """
+    sections: dict[str, list[str]] = {}
+    section: list[str] | None = None
+    for line in lines:
+        if line.startswith(_section_marker):
+            section = sections.setdefault(line.split()[1], [])
+        elif section is not None:
+            section.append(line)
+    if len(sections.get('stat', [])) == 0:
+        return None
+
+    cpu_busy, cpu_total = loader_monitoring._parse_cpu_times(
+        sections['stat'][0]
+    )
+    memory = loader_monitoring._parse_memory_usage(sections.get('meminfo', []))
+    counters = {
+        'cpu_busy': cpu_busy,
+        'cpu_total': cpu_total,
+        'memory': memory or 0.0,
+    }
+    block_devices = {
+        name for line in sections.get('blocks', []) for name in line.split()
+    }
+    counters.update(
+        _parse_disk_bytes(sections.get('diskstats', []), block_devices)
+    )
+    counters.update(_parse_network_bytes(sections.get('net/dev', [])))
+    return counters
+
+
+def split_host_samples(
+    samples: typing.Sequence[tuple[float, typing.Mapping[str, float]]],
+    *,
+    interval: float,
+    attack_times: typing.Sequence[tuple[float, float] | None],
+) -> typing.Sequence[spec.HostMetricsTimeSeries | None]:
+    """split samples into time series of each attack
+
+    attack_times are the start and end timestamps of each attack, or None
+    for attacks that were not run
+    """
+    time_series = []
+    for times in attack_times:
+        if times is None:
+            time_series.append(None)
+            continue
+        t_start, t_end = times
+        attack_samples = [
+            (timestamp - t_start, counters)
+            for timestamp, counters in samples
+            if t_start <= timestamp <= t_end
+        ]
+        time_series.append(
+            _create_host_metrics_time_series(
+                interval=interval, samples=attack_samples
+            )
+        )
+    return time_series
+
+
+def _create_host_metrics_time_series(
+    interval: float,
+    samples: typing.Sequence[tuple[float, typing.Mapping[str, float]]],
+) -> spec.HostMetricsTimeSeries | None:
+    """convert counters into utilization and bytes per second of intervals"""
+    if len(samples) < 2:
+        return None
+    time_series: spec.HostMetricsTimeSeries = {
+        'interval': interval,
+        'time': [],
+        'cpu': [],
+        'memory': [],
+        'disk_read': [],
+        'disk_write': [],
+        'network_receive': [],
+        'network_transmit': [],
+    }
+    rate_keys = [
+        'disk_read',
+        'disk_write',
+        'network_receive',
+        'network_transmit',
+    ]
+    for (t_before, before), (t_after, after) in zip(samples, samples[1:]):
+        dt = t_after - t_before
+        if dt <= 0:
+            continue
+        cpu_total = after['cpu_total'] - before['cpu_total']
+        if cpu_total > 0:
+            cpu = (after['cpu_busy'] - before['cpu_busy']) / cpu_total
+        else:
+            cpu = None
+        time_series['time'].append(t_before)  # type: ignore
+        time_series['cpu'].append(cpu)  # type: ignore
+        time_series['memory'].append(after['memory'])  # type: ignore
+        for key in rate_keys:
+            value = (after[key] - before[key]) / dt
+            time_series[key].append(value)  # type: ignore
+    return time_series
+
+
+def _parse_disk_bytes(
+    lines: typing.Sequence[str],
+    block_devices: typing.Collection[str],
+) -> typing.Mapping[str, float]:
+    """parse bytes read and written by physical disks from /proc/diskstats"""
+    read = 0.0
+    written = 0.0
+    for line in lines:
+        fields = line.split()
+        if len(fields) < 10:
+            continue
+        name = fields[2]
+
+        # partitions are not in /sys/block, skip virtual devices
+        if name not in block_devices:
+            continue
+        if name.startswith(('loop', 'ram', 'dm-', 'zram')):
+            continue
+
+        # sectors are 512 bytes regardless of device sector size
+        read += int(fields[5]) * 512
+        written += int(fields[9]) * 512
+    return {'disk_read': read, 'disk_write': written}
+
+
+def _parse_network_bytes(
+    lines: typing.Sequence[str],
+) -> typing.Mapping[str, float]:
+    """parse bytes received and transmitted by non-loopback interfaces
+
+    lines are from /proc/net/dev, whose two header lines have no counters
+    """
+    received = 0.0
+    transmitted = 0.0
+    for line in lines:
+        interface, _, data = line.partition(':')
+        if interface.strip() == 'lo':
+            continue
+        fields = data.split()
+        if len(fields) < 9:
+            continue
+        received += int(fields[0])
+        transmitted += int(fields[8])
+    return {'network_receive': received, 'network_transmit': transmitted}
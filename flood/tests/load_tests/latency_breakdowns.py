@@ -0,0 +1,258 @@
+"""break down latency by parameters of the calls that were sent
+
+each response is paired with its call by deep_utils, then latency is
+bucketed by:
+- block_number: block height queried by the call, in equal width bins
+- block_age: blocks between the chain head and block_number, in powers of 2
+- block_range: number of blocks in an eth_getLogs range, in powers of 2
+- response_bytes: size of the response body, in powers of 2
+
+each call of a batch is counted separately, with the latency and success of
+its whole batch
+
+archive nodes are often fast on recent state and slow on old state, which
+is hidden when latency is summarized as a single number per attack
+"""
+from __future__ import annotations
+
+import typing
+
+from ... import spec
+
+if typing.TYPE_CHECKING:
+    import numpy as np
+    import polars as pl
+
+
+default_block_number_bins = 10
+
+latency_breakdown_dimensions: typing.Sequence[str] = [
+    'block_number',
+    'block_age',
+    'block_range',
+    'response_bytes',
+]
+
+# index of block number param of each method
+block_number_param_indices: typing.Mapping[str, int] = {
+    'eth_getBlockByNumber': 0,
+    'eth_getBalance': 1,
+    'eth_getTransactionCount': 1,
+    'eth_getCode': 1,
+    'eth_getStorageAt': 2,
+    'eth_call': 1,
+    'eth_feeHistory': 1,
+    'trace_block': 0,
+    'trace_replayBlockTransactions': 0,
+}
+
+
+def get_call_block_number(call: typing.Any) -> int | None:
+    """get block height queried by call, or None if not a fixed height"""
+    if not isinstance(call, dict):
+        return None
+    method = call.get('method')
+    params = call.get('params')
+    if not isinstance(params, list):
+        return None
+    if method == 'eth_getLogs':
+        if len(params) == 0 or not isinstance(params[0], dict):
+            return None
+        return _parse_block_number(params[0].get('fromBlock'))
+    index = block_number_param_indices.get(method)  # type: ignore
+    if index is None or len(params) <= index:
+        return None
+    return _parse_block_number(params[index])
+
+
+def get_call_block_range(call: typing.Any) -> int | None:
+    """get number of blocks in range of an eth_getLogs call"""
+    if not isinstance(call, dict) or call.get('method') != 'eth_getLogs':
+        return None
+    params = call.get('params')
+    if not isinstance(params, list) or len(params) == 0:
+        return None
+    if not isinstance(params[0], dict):
+        return None
+    start_block = _parse_block_number(params[0].get('fromBlock'))
+    end_block = _parse_block_number(params[0].get('toBlock'))
+    if start_block is None or end_block is None:
+        return None
+    return end_block - start_block + 1
+
+
+def _parse_block_number(block: typing.Any) -> int | None:
+    """parse int or hex block number, tags like 'latest' are not fixed"""
+    if isinstance(block, bool):
+        return None
+    elif isinstance(block, int):
+        return block
+    elif isinstance(block, str) and block.startswith('0x'):
+        try:
+            return int(block, 16)
+        except ValueError:
+            return None
+    else:
+        return None
+
+
+def compute_latency_breakdowns(
+    df: pl.DataFrame,
+    response_calls: typing.Sequence[typing.Any],
+    n_block_number_bins: int | None = None,
+    head_block: int | None = None,
+) -> typing.Mapping[str, spec.LatencyBreakdown]:
+    """compute latency and success of each bin of each dimension
+
+    response_calls are the calls that caused each response of df, batches
+    are lists of calls
+
+    dimensions that do not apply to any call of the attack are omitted, block
+    number bins span the blocks of this attack's calls, so bins can differ
+    between attacks
+
+    block age is only computed if head_block, the block number of the node's
+    chain head when the attack started, is given
+    """
+    import numpy as np
+
+    if n_block_number_bins is None:
+        n_block_number_bins = default_block_number_bins
+
+    latencies = df['latency'].to_numpy() / 1e9
+    if 'deep_success' in df.columns:
+        successful = df['deep_success'].to_numpy().astype(bool)
+    else:
+        successful = (df['status_code'] == 200).to_numpy()
+
+    # expand batches into one row per call
+    rows = []
+    calls = []
+    for row, response_call in enumerate(response_calls):
+        if isinstance(response_call, list):
+            for call in response_call:
+                rows.append(row)
+                calls.append(call)
+        else:
+            rows.append(row)
+            calls.append(response_call)
+    call_rows = np.array(rows, dtype=int)
+    call_latencies = latencies[call_rows]
+    call_successful = successful[call_rows]
+
+    breakdowns: dict[str, spec.LatencyBreakdown] = {}
+
+    # block number of each call, in equal width bins over all calls
+    call_blocks = _to_array([get_call_block_number(call) for call in calls])
+    known_blocks = call_blocks[~np.isnan(call_blocks)]
+    if len(known_blocks) > 0:
+        edges = np.linspace(
+            known_blocks.min(),
+            known_blocks.max() + 1,
+            n_block_number_bins + 1,
+        )
+        edges = np.unique(np.floor(edges))
+        breakdowns['block_number'] = _compute_breakdown(
+            values=call_blocks,
+            edges=edges,
+            latencies=call_latencies,
+            successful=call_successful,
+        )
+
+    # blocks behind chain head of each call, in powers of 2
+    if head_block is not None and len(known_blocks) > 0:
+        call_ages = np.maximum(head_block - call_blocks, 0)
+        breakdowns['block_age'] = _compute_breakdown(
+            values=call_ages,
+            edges=_get_power_of_2_edges(call_ages),
+            latencies=call_latencies,
+            successful=call_successful,
+        )
+
+    # block range size of each eth_getLogs call, in powers of 2
+    call_ranges = _to_array([get_call_block_range(call) for call in calls])
+    if not np.isnan(call_ranges).all():
+        breakdowns['block_range'] = _compute_breakdown(
+            values=call_ranges,
+            edges=_get_power_of_2_edges(call_ranges),
+            latencies=call_latencies,
+            successful=call_successful,
+        )
+
+    # size of each response body, in powers of 2
+    if len(df) > 0:
+        values = df['bytes_in'].to_numpy().astype(float)
+        breakdowns['response_bytes'] = _compute_breakdown(
+            values=values,
+            edges=_get_power_of_2_edges(values),
+            latencies=latencies,
+            successful=successful,
+        )
+
+    return breakdowns
+
+
+def _to_array(
+    values: typing.Sequence[int | None],
+) -> np.ndarray[typing.Any, typing.Any]:
+    """convert values to float array, nan for calls without a value"""
+    import numpy as np
+
+    return np.array(
+        [np.nan if value is None else value for value in values],
+        dtype=float,
+    )
+
+
+def _get_power_of_2_edges(
+    values: np.ndarray[typing.Any, typing.Any],
+) -> np.ndarray[typing.Any, typing.Any]:
+    """get bin edges 0, 1, 2, 4, 8, ... that span values"""
+    import numpy as np
+
+    known = values[~np.isnan(values)]
+    if len(known) == 0 or known.max() < 1:
+        return np.array([0.0, 1.0])
+    n_powers = int(np.floor(np.log2(known.max()))) + 2
+    return np.concatenate([[0.0], 2.0 ** np.arange(n_powers)])
+
+
+def _compute_breakdown(
+    *,
+    values: np.ndarray[typing.Any, typing.Any],
+    edges: np.ndarray[typing.Any, typing.Any],
+    latencies: np.ndarray[typing.Any, typing.Any],
+    successful: np.ndarray[typing.Any, typing.Any],
+) -> spec.LatencyBreakdown:
+    import numpy as np
+
+    known = ~np.isnan(values)
+    bins = np.searchsorted(edges, values[known], side='right') - 1
+    bins = np.clip(bins, 0, len(edges) - 2)
+    known_latencies = latencies[known]
+    known_successful = successful[known]
+
+    breakdown: spec.LatencyBreakdown = {
+        'bin_lower': edges[:-1].tolist(),
+        'bin_upper': edges[1:].tolist(),
+        'requests': [],
+        'success': [],
+        'p50': [],
+        'p90': [],
+        'p99': [],
+    }
+    for b in range(len(edges) - 1):
+        mask = bins == b
+        n_requests = int(mask.sum())
+        breakdown['requests'].append(n_requests)  # type: ignore
+        if n_requests == 0:
+            for key in ['success', 'p50', 'p90', 'p99']:
+                breakdown[key].append(None)  # type: ignore
+            continue
+        p50, p90, p99 = np.percentile(known_latencies[mask], [50, 90, 99])
+        success = float(known_successful[mask].mean())
+        breakdown['success'].append(success)  # type: ignore
+        breakdown['p50'].append(float(p50))  # type: ignore
+        breakdown['p90'].append(float(p90))  # type: ignore
+        breakdown['p99'].append(float(p99))  # type: ignore
+    return breakdown
@@ -0,0 +1,150 @@
+"""compact mergeable latency histograms
+
+buckets are log-uniform, each doubling of latency is split into sub_buckets
+buckets, so every value is recorded within a relative error of
+2 ** (1 / sub_buckets), bucket 0 holds all latencies below min_latency
+
+histograms with the same sub_buckets and min_latency are merged by adding
+their counts, only non-empty buckets are stored
+"""
+from __future__ import annotations
+
+import typing
+
+from ... import spec
+
+if typing.TYPE_CHECKING:
+    import numpy as np
+
+
+default_histogram_sub_buckets = 32
+default_histogram_min_latency = 1e-6
+
+
+def create_latency_histogram(
+    latencies: typing.Sequence[float] | np.ndarray[typing.Any, typing.Any],
+    *,
+    sub_buckets: int | None = None,
+    min_latency: float | None = None,
+) -> spec.LatencyHistogram:
+    """create histogram from latencies in seconds"""
+    import numpy as np
+
+    if sub_buckets is None:
+        sub_buckets = default_histogram_sub_buckets
+    if min_latency is None:
+        min_latency = default_histogram_min_latency
+
+    indices = get_latency_bucket_indices(
+        np.asarray(latencies, dtype=float),
+        sub_buckets=sub_buckets,
+        min_latency=min_latency,
+    )
+    buckets, counts = np.unique(indices, return_counts=True)
+    return {
+        'sub_buckets': sub_buckets,
+        'min_latency': min_latency,
+        'buckets': buckets.tolist(),
+        'counts': counts.tolist(),
+    }
+
+
+def get_latency_bucket_indices(
+    latencies: np.ndarray[typing.Any, typing.Any],
+    *,
+    sub_buckets: int,
+    min_latency: float,
+) -> np.ndarray[typing.Any, typing.Any]:
+    import numpy as np
+
+    indices = np.zeros(len(latencies), dtype=np.int64)
+    above = latencies >= min_latency
+    indices[above] = (
+        np.floor(np.log2(latencies[above] / min_latency) * sub_buckets) + 1
+    )
+    return indices
+
+
+def get_latency_bucket_bounds(
+    bucket: int,
+    *,
+    sub_buckets: int,
+    min_latency: float,
+) -> tuple[float, float]:
+    """get lower and upper bound of bucket in seconds"""
+    if bucket == 0:
+        lower = 0.0
+    else:
+        lower = min_latency * 2 ** ((bucket - 1) / sub_buckets)
+    upper = min_latency * 2 ** (bucket / sub_buckets)
+    return lower, upper
+
+
+def merge_latency_histograms(
+    histograms: typing.Sequence[spec.LatencyHistogram | None],
+) -> spec.LatencyHistogram:
+    """merge histograms, e.g. from multiple attacks, nodes, or runs"""
+    use_histograms = [
+        histogram for histogram in histograms if histogram is not None
+    ]
+    if len(use_histograms) == 0:
+        raise Exception('no histograms to merge')
+    sub_buckets = use_histograms[0]['sub_buckets']
+    min_latency = use_histograms[0]['min_latency']
+    counts: dict[int, int] = {}
+    for histogram in use_histograms:
+        if (
+            histogram['sub_buckets'] != sub_buckets
+            or histogram['min_latency'] != min_latency
+        ):
+            raise Exception('histograms use different buckets')
+        for bucket, count in zip(histogram['buckets'], histogram['counts']):
+            counts[bucket] = counts.get(bucket, 0) + count
+    buckets = sorted(counts.keys())
+    return {
+        'sub_buckets': sub_buckets,
+        'min_latency': min_latency,
+        'buckets': buckets,
+        'counts': [counts[bucket] for bucket in buckets],
+    }
+
+
+def get_latency_histogram_quantile(
+    histogram: spec.LatencyHistogram,
+    quantile: float,
+) -> float | None:
+    """get upper bound of the bucket that contains quantile, e.g. 0.999"""
+    total = sum(histogram['counts'])
+    if total == 0:
+        return None
+    target = quantile * total
+    cumulative = 0
+    for bucket, count in zip(histogram['buckets'], histogram['counts']):
+        cumulative += count
+        if cumulative >= target:
+            break
+    return get_latency_bucket_bounds(
+        bucket,
+        sub_buckets=histogram['sub_buckets'],
+        min_latency=histogram['min_latency'],
+    )[1]
+
+
+def get_latency_histogram_cdf(
+    histogram: spec.LatencyHistogram,
+) -> tuple[typing.Sequence[float], typing.Sequence[float]]:
+    """get upper bound of each bucket and fraction of latencies up to it"""
+    total = sum(histogram['counts'])
+    latencies = []
+    fractions = []
+    cumulative = 0
+    for bucket, count in zip(histogram['buckets'], histogram['counts']):
+        cumulative += count
+        upper = get_latency_bucket_bounds(
+            bucket,
+            sub_buckets=histogram['sub_buckets'],
+            min_latency=histogram['min_latency'],
+        )[1]
+        latencies.append(upper)
+        fractions.append(cumulative / total)
+    return latencies, fractions
@@ -5,13 +5,23 @@ import typing
 import flood
 
 
+# closed-loop throughput is not known in advance, calls are cycled if exceeded
+concurrency_calls_per_worker_second = 10
+
+
 def estimate_call_count(
     *,
     rates: typing.Sequence[int],
     duration: int | None = None,
     durations: typing.Sequence[int] | None = None,
     n_repeats: int | None = None,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
 ) -> int:
+    """for concurrency mode, rates are the number of concurrent workers"""
+    if mode == 'concurrency':
+        rates = [rate * concurrency_calls_per_worker_second for rate in rates]
+
     if duration is not None:
         n_calls = sum(rate * duration for rate in rates)
     elif durations is not None:
@@ -25,6 +35,8 @@ def estimate_call_count(
 
     if n_repeats is not None:
         n_calls *= n_repeats
+    if batch_size is not None:
+        n_calls *= batch_size
 
     return n_calls
 
@@ -38,10 +50,23 @@ def create_load_test(
     | typing.Sequence[flood.VegetaArgs]
     | None = None,
     repeat_calls: bool = False,
+    batch_size: int | None = None,
+    mode: flood.LoadTestMode | None = None,
+    think_time: float | None = None,
 ) -> typing.Sequence[flood.VegetaAttack]:
+    """if batch_size is given, each request is a batch of batch_size calls
+
+    for concurrency mode, rates are the number of concurrent workers and each
+    worker waits think_time seconds between receiving a response and sending
+    its next request
+    """
     # validate inputs
     if len(rates) == 0:
         raise Exception('must specify at least one rate')
+    if batch_size is not None and batch_size < 1:
+        raise Exception('batch_size must be at least 1')
+    if think_time is not None and mode != 'concurrency':
+        raise Exception('think_time only used in concurrency mode')
 
     # pluralize singular durations
     if durations is None:
@@ -61,32 +86,83 @@ def create_load_test(
     else:
         raise Exception('invalid input')
 
-    # partition calls into individual attacks
+    # partition calls into individual attacks, slicing keeps lazy calls lazy
     if not repeat_calls:
         attacks_calls: typing.MutableSequence[typing.Sequence[flood.Call]] = []
-        calls_iter = iter(calls)
+        start = 0
         for rate, duration in zip(rates, durations):
-            n_attack_calls = rate * duration
-            attack_calls = []
-            for i in range(n_attack_calls):
-                attack_calls.append(next(calls_iter))
-            attacks_calls.append(attack_calls)
+            n_attack_calls = estimate_call_count(
+                rates=[rate], duration=duration, mode=mode
+            )
+            if batch_size is not None:
+                n_attack_calls *= batch_size
+            if start + n_attack_calls > len(calls):
+                raise Exception('not enough calls for load test')
+            attacks_calls.append(calls[start : start + n_attack_calls])
+            start += n_attack_calls
     else:
         attacks_calls = [calls] * len(rates)
     assert len(attacks_calls) == len(rates)
 
+    # group calls into batches
+    if batch_size is not None:
+        attacks_calls = [
+            create_call_batches(attack_calls, batch_size=batch_size)
+            for attack_calls in attacks_calls
+        ]
+
     # create load tests
     load_test: list[flood.VegetaAttack] = []
     for rate, duration, a_calls, attack_kwargs in zip(
         rates, durations, attacks_calls, use_vegeta_args
     ):
-        attack: flood.VegetaAttack = {
-            'rate': rate,
-            'duration': duration,
-            'calls': a_calls,
-            'vegeta_args': attack_kwargs,
-        }
+        if mode == 'concurrency':
+            attack: flood.VegetaAttack = {
+                'rate': 0,
+                'duration': duration,
+                'calls': a_calls,
+                'vegeta_args': attack_kwargs,
+                'concurrency': rate,
+                'think_time': think_time,
+                'offsets': None,
+            }
+        else:
+            attack = {
+                'rate': rate,
+                'duration': duration,
+                'calls': a_calls,
+                'vegeta_args': attack_kwargs,
+                'concurrency': None,
+                'think_time': None,
+                'offsets': None,
+            }
         load_test.append(attack)
 
     return load_test
 
+
+def create_call_batches(
+    calls: typing.Sequence[flood.Call],
+    batch_size: int,
+) -> typing.Sequence[typing.Sequence[flood.Call]]:
+    """group calls into JSON-RPC batches, final batch may be smaller"""
+    return CallBatches(calls, batch_size)
+
+
+class CallBatches(typing.Sequence[typing.Sequence[typing.Any]]):
+    """JSON-RPC batches of calls, each batch is only built when accessed"""
+
+    def __init__(self, calls: typing.Sequence[flood.Call], batch_size: int):
+        if batch_size < 1:
+            raise Exception('batch_size must be at least 1')
+        self.calls = calls
+        self.batch_size = batch_size
+
+    def __len__(self) -> int:
+        return -(-len(self.calls) // self.batch_size)
+
+    def __getitem__(self, item: typing.Any) -> typing.Any:
+        if isinstance(item, slice):
+            return [self[i] for i in range(len(self))[item]]
+        start = range(len(self))[item] * self.batch_size
+        return list(self.calls[start : start + self.batch_size])
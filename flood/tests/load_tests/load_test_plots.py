@@ -17,6 +17,11 @@ def plot_load_test_results(
     plot_success_rate: bool = True,
     plot_throughput: bool = True,
     plot_latency: bool = True,
+    plot_time_series: bool = True,
+    plot_latency_distribution: bool = True,
+    plot_node_metrics: bool = True,
+    plot_host_metrics: bool = True,
+    plot_latency_breakdowns: bool = True,
 ) -> None:
     import os
     import matplotlib.pyplot as plt  # type: ignore
@@ -66,6 +71,144 @@ def plot_load_test_results(
         else:
             plt.show()
 
+    # latency distribution graphs
+    has_histograms = any(
+        histogram is not None
+        for output in outputs.values()
+        for histogram in output.get('latency_histogram', [])
+    )
+    if plot_latency_distribution and has_histograms:
+        plt.figure()
+        plot_latency_cdf(outputs, test_name=test_name, colors=colors)
+        if output_dir is not None:
+            path = os.path.join(
+                output_dir, 'latency_cdf' + file_suffix + '.png'
+            )
+            plt.savefig(path)
+        else:
+            plt.show()
+
+        plt.figure()
+        plot_latency_histogram(outputs, test_name=test_name, colors=colors)
+        if output_dir is not None:
+            path = os.path.join(
+                output_dir, 'latency_histogram' + file_suffix + '.png'
+            )
+            plt.savefig(path)
+        else:
+            plt.show()
+
+    # time series graphs
+    has_time_series = any(
+        output.get('deep_time_series') is not None
+        for output in outputs.values()
+    )
+    if plot_time_series and has_time_series:
+        plt.figure()
+        plot_load_test_latency_over_time(
+            outputs,  # type: ignore
+            test_name=test_name,
+            yscale_log=latency_yscale_log,
+            colors=colors,
+        )
+        if output_dir is not None:
+            path = os.path.join(
+                output_dir, 'latency_over_time' + file_suffix + '.png'
+            )
+            plt.savefig(path)
+        else:
+            plt.show()
+
+        plt.figure()
+        plot_load_test_throughput_over_time(
+            outputs, test_name=test_name, colors=colors  # type: ignore
+        )
+        if output_dir is not None:
+            path = os.path.join(
+                output_dir, 'throughput_over_time' + file_suffix + '.png'
+            )
+            plt.savefig(path)
+        else:
+            plt.show()
+
+    # node metrics graphs
+    node_metric_categories = sorted(
+        {
+            category
+            for output in outputs.values()
+            for node_metrics in output.get('node_metrics') or []
+            if node_metrics is not None
+            for category in node_metrics['categories'].keys()
+        }
+    )
+    if plot_node_metrics:
+        for category in node_metric_categories:
+            plt.figure()
+            plot_node_metrics_over_time(
+                outputs,  # type: ignore
+                category=category,
+                test_name=test_name,
+                colors=colors,
+            )
+            if output_dir is not None:
+                path = os.path.join(
+                    output_dir,
+                    'node_' + category + '_over_time' + file_suffix + '.png',
+                )
+                plt.savefig(path)
+            else:
+                plt.show()
+
+    # host metrics graphs
+    has_host_metrics = any(
+        output.get('host_metrics') is not None for output in outputs.values()
+    )
+    if plot_host_metrics and has_host_metrics:
+        for name, metrics in host_metric_figures.items():
+            plt.figure()
+            plot_host_metrics_over_time(
+                outputs,  # type: ignore
+                metrics=metrics,
+                test_name=test_name,
+                colors=colors,
+            )
+            if output_dir is not None:
+                path = os.path.join(
+                    output_dir,
+                    'host_' + name + '_over_time' + file_suffix + '.png',
+                )
+                plt.savefig(path)
+            else:
+                plt.show()
+
+    # latency breakdown graphs
+    breakdown_dimensions = [
+        dimension
+        for dimension in flood.tests.load_tests.latency_breakdown_dimensions
+        if any(
+            breakdowns is not None and dimension in breakdowns
+            for output in outputs.values()
+            for breakdowns in output.get('deep_latency_breakdowns') or []
+        )
+    ]
+    if plot_latency_breakdowns:
+        for dimension in breakdown_dimensions:
+            plt.figure()
+            plot_latency_by_parameter(
+                outputs,  # type: ignore
+                dimension=dimension,
+                test_name=test_name,
+                colors=colors,
+            )
+            if output_dir is not None:
+                path = os.path.join(
+                    output_dir,
+                    'latency_by_' + dimension + file_suffix + '.png',
+                )
+                plt.savefig(path)
+            else:
+                plt.show()
+
     # deep graphs
     has_deep_outputs = any(
         output.get('deep_metrics') is not None for output in outputs.values()
@@ -81,6 +224,7 @@ def plot_load_test_results(
             file_suffix='_successful_calls',
             plot_success_rate=False,
             plot_throughput=False,
+            plot_latency_distribution=False,
             output_dir=output_dir,
             latency_yscale_log=latency_yscale_log,
             colors=colors,
@@ -95,6 +239,7 @@ def plot_load_test_results(
             file_suffix='_failed_calls',
             plot_success_rate=False,
             plot_throughput=False,
+            plot_latency_distribution=False,
             output_dir=output_dir,
             latency_yscale_log=latency_yscale_log,
             colors=colors,
@@ -197,29 +342,15 @@ def plot_load_test_result_metrics(
     import toolplot
 
     plot_colors = flood.user_io.plot_colors
+    concurrency = _uses_concurrency(results)
+    if concurrency and title is not None:
+        title = title.replace('Request Rate', 'Concurrency')
 
     if colors is None:
         colors = {key: color for key, color in zip(results.keys(), plot_colors)}
 
     for name, result in results.items():
-        # determine colors
-        result_colors = colors.get(name)
-        if isinstance(result_colors, str):
-            if result_colors in plot_colors:
-                if len(metrics) == 1:
-                    result_colors = [plot_colors[result_colors][1]]
-                else:
-                    result_colors = plot_colors[result_colors]
-            else:
-                result_colors = [result_colors] * len(metrics)
-        elif isinstance(result_colors, list):
-            assert len(result_colors) >= len(metrics), 'not enough colors'
-        elif isinstance(result_colors, dict):
-            for metric in metrics:
-                assert metric in result_colors, 'missing color for ' + metric
-            result_colors = [result_colors[metric] for metric in metrics]
-        else:
-            raise Exception('invalid color format')
+        result_colors = _get_result_colors(colors.get(name), metrics)
 
         # plot
         for zorder, metric, color in zip(
@@ -228,8 +359,12 @@ def plot_load_test_result_metrics(
             label = name
             if len(metrics) > 1:
                 label += ' ' + metric
+            if concurrency:
+                xvalues = result['target_concurrency']
+            else:
+                xvalues = result['target_rate']
             plt.plot(
-                result['target_rate'],
+                xvalues,
                 result[metric],  # type: ignore
                 '.-',
                 markersize=20,
@@ -246,7 +381,10 @@ def plot_load_test_result_metrics(
     if ymin is not None:
         ylim = plt.ylim()
         plt.ylim([ymin, ylim[1]])  # type: ignore
-    xlabel = 'requests per second'
+    if concurrency:
+        xlabel = 'concurrent workers'
+    else:
+        xlabel = 'requests per second'
     if test_name is not None:
         xlabel += '\n[' + test_name + ']'
     toolplot.set_labels(
@@ -256,3 +394,503 @@ def plot_load_test_result_metrics(
     )
     plt.legend(loc='center right')
 
+
+def plot_load_test_latency_over_time(
+    results: typing.Mapping[str, flood.LoadTestOutput],
+    colors: typing.Mapping[
+        str,
+        str | typing.Sequence[str] | typing.Mapping[str, str],
+    ]
+    | None = None,
+    metrics: typing.Sequence[str] = ['p99', 'p90', 'p50'],
+    test_name: str | None = None,
+    yscale_log: bool = False,
+) -> None:
+    import matplotlib.pyplot as plt
+
+    plot_load_test_time_series_metrics(
+        results=results,
+        metrics=metrics,
+        colors=colors,
+        test_name=test_name,
+        title='Latency over Time\n(lower is better)',
+        ylabel='latency (seconds)',
+        yscale_log=yscale_log,
+    )
+    plt.legend(loc='upper left')
+
+
+def plot_load_test_throughput_over_time(
+    results: typing.Mapping[str, flood.LoadTestOutput],
+    colors: typing.Mapping[
+        str,
+        str | typing.Sequence[str] | typing.Mapping[str, str],
+    ]
+    | None = None,
+    test_name: str | None = None,
+) -> None:
+    import matplotlib.pyplot as plt
+
+    plot_load_test_time_series_metrics(
+        results=results,
+        metrics=['throughput'],
+        colors=colors,
+        test_name=test_name,
+        title='Throughput over Time\n(higher is better)',
+        ylabel='throughput\n(responses per second)',
+        ymin=0,
+    )
+    plt.legend(loc='upper left')
+
+
+def plot_load_test_time_series_metrics(
+    results: typing.Mapping[str, flood.LoadTestOutput],
+    metrics: typing.Sequence[str],
+    *,
+    colors: typing.Mapping[
+        str,
+        str | typing.Sequence[str] | typing.Mapping[str, str],
+    ]
+    | None = None,
+    test_name: str | None = None,
+    title: str | None = None,
+    ylabel: str | None = None,
+    ymin: float | int | None = None,
+    yscale_log: bool = False,
+    time_series_key: str = 'deep_time_series',
+) -> None:
+    """plot time series of each attack end to end, boundaries are dotted"""
+    import matplotlib.pyplot as plt
+    import toolplot
+
+    if colors is None:
+        colors = dict(zip(results.keys(), flood.user_io.plot_colors.keys()))
+
+    boundaries = set()
+    for name, result in results.items():
+        time_series = result.get(time_series_key)  # type: ignore
+        if time_series is None:
+            continue
+        result_colors = _get_result_colors(colors.get(name), metrics)
+        times, values = _concatenate_time_series(
+            time_series, result['target_duration'], metrics
+        )
+        boundaries.update(times['boundaries'])
+        for zorder, metric, color in zip(
+            range(len(metrics)), metrics, result_colors
+        ):
+            label = name
+            if len(metrics) > 1:
+                label += ' ' + metric
+            plt.plot(
+                times['time'],
+                values[metric],
+                '-',
+                color=color,
+                label=label,
+                zorder=zorder,
+            )
+
+    for boundary in sorted(boundaries):
+        plt.axvline(boundary, color='gray', linestyle=':', linewidth=1)
+
+    if yscale_log:
+        plt.yscale('log')
+    if ymin is not None:
+        ylim = plt.ylim()
+        plt.ylim([ymin, ylim[1]])  # type: ignore
+    xlabel = 'time since start of test (seconds)'
+    if test_name is not None:
+        xlabel += '\n[' + test_name + ']'
+    toolplot.set_labels(
+        title=title,
+        xlabel=xlabel,
+        ylabel=ylabel,
+    )
+
+
+def _concatenate_time_series(
+    time_series: typing.Sequence[flood.LoadTestTimeSeries | None],
+    target_durations: typing.Sequence[int],
+    metrics: typing.Sequence[str],
+) -> tuple[
+    typing.Mapping[str, typing.Sequence[float]],
+    typing.Mapping[str, typing.Sequence[float | None]],
+]:
+    """place time series of consecutive attacks end to end"""
+    times: dict[str, list[float]] = {'time': [], 'boundaries': []}
+    values: dict[str, list[float | None]] = {metric: [] for metric in metrics}
+    offset = 0.0
+    for attack_series, target_duration in zip(time_series, target_durations):
+        if offset > 0:
+            times['boundaries'].append(offset)
+        if attack_series is None:
+            offset += target_duration
+            continue
+        for t in attack_series['time']:
+            times['time'].append(offset + t)
+        for metric in metrics:
+            values[metric].extend(attack_series[metric])  # type: ignore
+        length = len(attack_series['time']) * attack_series['interval']
+        offset += max(target_duration, length)
+    return times, values
+
+
+def plot_node_metrics_over_time(
+    results: typing.Mapping[str, flood.LoadTestOutput],
+    category: str,
+    colors: typing.Mapping[str, str] | None = None,
+    test_name: str | None = None,
+) -> None:
+    """plot total of a category's node metrics, counters are plotted as rates"""
+    import matplotlib.pyplot as plt
+    import toolplot
+
+    if colors is None:
+        colors = dict(zip(results.keys(), flood.user_io.plot_colors.keys()))
+
+    boundaries = set()
+    is_rate = False
+    for name, result in results.items():
+        node_metrics = result.get('node_metrics')
+        if node_metrics is None:
+            continue
+        attack_series = []
+        for attack_metrics in node_metrics:
+            if attack_metrics is None:
+                attack_series.append(None)
+            else:
+                series, is_rate = _get_node_metric_category_series(
+                    attack_metrics, category
+                )
+                attack_series.append(series)
+        times, values = _concatenate_time_series(
+            attack_series, result['target_duration'], ['value']  # type: ignore
+        )
+        boundaries.update(times['boundaries'])
+        color = _get_result_colors(colors.get(name), ['value'])[0]
+        plt.plot(times['time'], values['value'], '-', color=color, label=name)
+
+    for boundary in sorted(boundaries):
+        plt.axvline(boundary, color='gray', linestyle=':', linewidth=1)
+
+    ylabel = category
+    if is_rate:
+        ylabel += '\n(per second)'
+    xlabel = 'time since start of test (seconds)'
+    if test_name is not None:
+        xlabel += '\n[' + test_name + ']'
+    toolplot.set_labels(
+        title='Node ' + category + ' over Time',
+        xlabel=xlabel,
+        ylabel=ylabel,
+    )
+    plt.legend(loc='upper left')
+
+
+host_metric_figures = {
+    'cpu': ['cpu'],
+    'memory': ['memory'],
+    'disk_io': ['disk_read', 'disk_write'],
+    'network_io': ['network_receive', 'network_transmit'],
+}
+
+host_metric_labels = {
+    'cpu': ('Host CPU Utilization over Time', 'cpu utilization'),
+    'memory': ('Host Memory Usage over Time', 'fraction of memory used'),
+    'disk_io': ('Host Disk IO over Time', 'disk io\n(bytes per second)'),
+    'network_io': (
+        'Host Network IO over Time',
+        'network io\n(bytes per second)',
+    ),
+}
+
+
+def plot_host_metrics_over_time(
+    results: typing.Mapping[str, flood.LoadTestOutput],
+    metrics: typing.Sequence[str],
+    colors: typing.Mapping[
+        str,
+        str | typing.Sequence[str] | typing.Mapping[str, str],
+    ]
+    | None = None,
+    test_name: str | None = None,
+) -> None:
+    """plot system metrics of the host of each node during attacks"""
+    import matplotlib.pyplot as plt
+
+    for name, figure_metrics in host_metric_figures.items():
+        if list(metrics) == figure_metrics:
+            title, ylabel = host_metric_labels[name]
+            break
+    else:
+        title = 'Host Metrics over Time'
+        ylabel = ', '.join(metrics)
+
+    plot_load_test_time_series_metrics(
+        results=results,
+        metrics=metrics,
+        colors=colors,
+        test_name=test_name,
+        title=title,
+        ylabel=ylabel,
+        ymin=0,
+        time_series_key='host_metrics',
+    )
+    plt.legend(loc='upper left')
+
+
+def _get_node_metric_category_series(
+    node_metrics: flood.NodeMetricsTimeSeries,
+    category: str,
+) -> tuple[typing.Mapping[str, typing.Any] | None, bool]:
+    """sum series of category in each scrape, converting counters to rates"""
+    names = node_metrics['categories'].get(category)
+    if names is None or len(names) == 0:
+        return None, False
+
+    times = list(node_metrics['time'])
+    totals: list[float | None] = []
+    for i in range(len(times)):
+        values = [node_metrics['series'][name][i] for name in names]
+        present = [value for value in values if value is not None]
+        if len(present) > 0:
+            totals.append(sum(present))
+        else:
+            totals.append(None)
+
+    is_rate = all(
+        flood.tests.load_tests.is_counter_series(name) for name in names
+    )
+    if is_rate:
+        rates: list[float | None] = []
+        for i in range(1, len(times)):
+            previous = totals[i - 1]
+            current = totals[i]
+            dt = times[i] - times[i - 1]
+            if previous is None or current is None or dt <= 0:
+                rates.append(None)
+            else:
+                rates.append((current - previous) / dt)
+        times = times[1:]
+        totals = rates
+
+    series = {
+        'interval': node_metrics['interval'],
+        'time': times,
+        'value': totals,
+    }
+    return series, is_rate
+
+
+latency_breakdown_labels = {
+    'block_number': 'block number',
+    'block_age': 'block age (blocks behind head)',
+    'block_range': 'eth_getLogs range size (blocks)',
+    'response_bytes': 'response size (bytes)',
+}
+
+
+def plot_latency_by_parameter(
+    results: typing.Mapping[str, flood.LoadTestOutput],
+    dimension: str,
+    colors: typing.Mapping[str, str] | None = None,
+    metric: str = 'p90',
+    attack_index: int = -1,
+    test_name: str | None = None,
+) -> None:
+    """plot latency of calls binned by block, range size, or response size
+
+    only a single attack of each result is plotted, by default the last
+    """
+    import matplotlib.pyplot as plt
+    import toolplot
+
+    if colors is None:
+        colors = dict(zip(results.keys(), flood.user_io.plot_colors.keys()))
+
+    for name, result in results.items():
+        all_breakdowns = result.get('deep_latency_breakdowns')
+        if all_breakdowns is None or len(all_breakdowns) == 0:
+            continue
+        breakdowns = all_breakdowns[attack_index]
+        if breakdowns is None or dimension not in breakdowns:
+            continue
+        breakdown = breakdowns[dimension]
+
+        # block numbers are plotted at bin centers, sizes at bin upper bounds
+        if dimension == 'block_number':
+            xs = [
+                (lower + upper) / 2
+                for lower, upper in zip(
+                    breakdown['bin_lower'], breakdown['bin_upper']
+                )
+            ]
+        else:
+            xs = list(breakdown['bin_upper'])
+        color = _get_result_colors(colors.get(name), [metric])[0]
+        plt.plot(
+            xs,
+            breakdown[metric],  # type: ignore
+            '.-',
+            color=color,
+            markersize=10,
+            label=name,
+        )
+
+    if dimension != 'block_number':
+        plt.xscale('log', base=2)
+    ylim = plt.ylim()
+    plt.ylim([0, ylim[1]])  # type: ignore
+    label = latency_breakdown_labels.get(dimension, dimension)
+    xlabel = label
+    if test_name is not None:
+        xlabel += '\n[' + test_name + ']'
+    toolplot.set_labels(
+        title=metric + ' Latency by ' + label,
+        xlabel=xlabel,
+        ylabel=metric + ' latency (seconds)',
+    )
+    plt.legend(loc='upper left')
+
+
+def plot_latency_cdf(
+    results: typing.Mapping[str, flood.LoadTestOutput]
+    | typing.Mapping[str, flood.LoadTestDeepOutput],
+    colors: typing.Mapping[str, str] | None = None,
+    test_name: str | None = None,
+    attack_index: int | None = None,
+) -> None:
+    """plot fraction of requests at or below each latency
+
+    histograms of all attacks are merged unless attack_index is given
+    """
+    import matplotlib.pyplot as plt
+    import toolplot
+
+    if colors is None:
+        colors = dict(zip(results.keys(), flood.user_io.plot_colors.keys()))
+
+    for name, result in results.items():
+        histogram = _get_result_histogram(result, attack_index)
+        if histogram is None:
+            continue
+        latencies, fractions = flood.tests.load_tests.get_latency_histogram_cdf(
+            histogram
+        )
+        color = _get_result_colors(colors.get(name), ['latency'])[0]
+        plt.step(latencies, fractions, where='post', color=color, label=name)
+
+    plt.xscale('log')
+    plt.ylim([-0.03, 1.03])
+    xlabel = 'latency (seconds)'
+    if test_name is not None:
+        xlabel += '\n[' + test_name + ']'
+    toolplot.set_labels(
+        title='Latency CDF\n(further left is better)',
+        xlabel=xlabel,
+        ylabel='fraction of requests',
+    )
+    plt.legend(loc='lower right')
+
+
+def plot_latency_histogram(
+    results: typing.Mapping[str, flood.LoadTestOutput]
+    | typing.Mapping[str, flood.LoadTestDeepOutput],
+    colors: typing.Mapping[str, str] | None = None,
+    test_name: str | None = None,
+    attack_index: int | None = None,
+) -> None:
+    """plot number of requests in each latency bucket
+
+    histograms of all attacks are merged unless attack_index is given
+    """
+    import matplotlib.pyplot as plt
+    import toolplot
+
+    if colors is None:
+        colors = dict(zip(results.keys(), flood.user_io.plot_colors.keys()))
+
+    for name, result in results.items():
+        histogram = _get_result_histogram(result, attack_index)
+        if histogram is None or len(histogram['buckets']) == 0:
+            continue
+        edges = [
+            flood.tests.load_tests.get_latency_bucket_bounds(
+                bucket,
+                sub_buckets=histogram['sub_buckets'],
+                min_latency=histogram['min_latency'],
+            )[1]
+            for bucket in histogram['buckets']
+        ]
+        color = _get_result_colors(colors.get(name), ['latency'])[0]
+        plt.step(
+            edges,
+            histogram['counts'],
+            where='pre',
+            color=color,
+            label=name,
+        )
+
+    plt.xscale('log')
+    plt.ylim([0, plt.ylim()[1]])
+    xlabel = 'latency (seconds)'
+    if test_name is not None:
+        xlabel += '\n[' + test_name + ']'
+    toolplot.set_labels(
+        title='Latency Histogram',
+        xlabel=xlabel,
+        ylabel='requests',
+    )
+    plt.legend(loc='upper right')
+
+
+def _get_result_histogram(
+    result: flood.LoadTestOutput | flood.LoadTestDeepOutput,
+    attack_index: int | None,
+) -> flood.LatencyHistogram | None:
+    histograms = result.get('latency_histogram')
+    if histograms is None:
+        return None
+    if attack_index is not None:
+        return histograms[attack_index]
+    if all(histogram is None for histogram in histograms):
+        return None
+    return flood.tests.load_tests.merge_latency_histograms(histograms)
+
+
+def _get_result_colors(
+    result_colors: str | typing.Sequence[str] | typing.Mapping[str, str] | None,
+    metrics: typing.Sequence[str],
+) -> typing.Sequence[str]:
+    """get color of each metric for a single result"""
+    plot_colors = flood.user_io.plot_colors
+    if isinstance(result_colors, str):
+        if result_colors in plot_colors:
+            if len(metrics) == 1:
+                return [plot_colors[result_colors][1]]
+            else:
+                return plot_colors[result_colors]  # type: ignore
+        else:
+            return [result_colors] * len(metrics)
+    elif isinstance(result_colors, list):
+        assert len(result_colors) >= len(metrics), 'not enough colors'
+        return result_colors
+    elif isinstance(result_colors, dict):
+        for metric in metrics:
+            assert metric in result_colors, 'missing color for ' + metric
+        return [result_colors[metric] for metric in metrics]
+    else:
+        raise Exception('invalid color format')
+
+
+def _uses_concurrency(
+    results: typing.Mapping[str, flood.LoadTestOutput]
+    | typing.Mapping[str, flood.LoadTestDeepOutput],
+) -> bool:
+    """whether results are from closed-loop attacks with fixed concurrency"""
+    return any(
+        concurrency is not None
+        for result in results.values()
+        for concurrency in result.get('target_concurrency', [])
+    )
@@ -462,6 +462,38 @@ _test_template_cells: notebook_io.NotebookTemplate = [
         """,  # noqa: E501
         'inputs': [],
     },
+    {
+        # show latency distributions
+        'type': 'code',
+        'content': """
+            # show latency distributions
+
+            import matplotlib.pyplot as plt
+
+            if any(result.get('latency_histogram') for result in results.values()):
+                plt.figure()
+                flood.tests.load_tests.plot_latency_cdf(results, colors=colors, test_name=test_name)
+                plt.show()
+                plt.figure()
+                flood.tests.load_tests.plot_latency_histogram(results, colors=colors, test_name=test_name)
+                plt.show()
+
+                quantiles = [0.5, 0.9, 0.99, 0.999]
+                rows = []
+                for name, result in results.items():
+                    histogram = flood.tests.load_tests.merge_latency_histograms(result['latency_histogram'])
+                    rows.append(
+                        [name]
+                        + [
+                            flood.tests.load_tests.get_latency_histogram_quantile(histogram, quantile)
+                            for quantile in quantiles
+                        ]
+                    )
+                toolstr.print_text_box('Latency quantiles of all attacks (from histograms)')
+                toolstr.print_table(rows, labels=['node'] + ['p' + str(quantile * 100).rstrip('0').rstrip('.') for quantile in quantiles])
+        """,  # noqa: E501
+        'inputs': [],
+    },
     {
         # show errors
         'type': 'code',
@@ -480,6 +512,18 @@ _test_template_cells: notebook_io.NotebookTemplate = [
                     print('-', error)
                 if n != len(results) - 1:
                     print()
+
+            # error codes and messages of failed calls, from deep checks
+            failed_results = {{
+                name: result['deep_metrics']['failed']
+                for name, result in results.items()
+                if result.get('deep_metrics') is not None
+            }}
+            if len(failed_results) > 0:
+                print()
+                flood.user_io.print_error_count_tables(failed_results)
+                print()
+                flood.user_io.print_slowest_calls_tables(results, max_calls=10)
         """,  # noqa: E501
         'inputs': [],
     },
@@ -492,15 +536,23 @@ _test_template_cells: notebook_io.NotebookTemplate = [
             for name in results.keys():
                 toolstr.print_text_box(name + " Complete Results")
                 df = pl.DataFrame(results[name])
-                df = df.drop(
+                drop_columns = [
                     "status_codes",
                     "errors",
                     "first_request_timestamp",
                     "last_request_timestamp",
                     "last_response_timestamp",
-                )
+                    "latency_histogram",
+                    "loader_metrics",
+                    "node_metrics",
+                    "host_metrics",
+                    "deep_rpc_error_pairs",
+                    "deep_slowest_calls",
+                    "deep_latency_breakdowns",
+                ]
+                df = df.drop([column for column in drop_columns if column in df.columns])
                 IPython.display.display(df)
-        """,
+        """,  # noqa: E501
         'inputs': [],
     },
 ]
@@ -5,6 +5,12 @@ import typing
 import flood
 from flood import user_io
 from flood import spec
+from . import deep_utils
+from . import distributed_load_tests
+from . import host_monitoring
+from . import loader_monitoring
+from . import native
+from . import raw_outputs
 from . import vegeta
 
 if typing.TYPE_CHECKING:
@@ -20,13 +26,42 @@ def run_load_tests(
     | None = None,
     verbose: bool | int = False,
     include_deep_output: typing.Sequence[spec.DeepOutput] | None = None,
+    engine: spec.LoadEngine | None = None,
+    parallel: bool = False,
+    stop_conditions: spec.StopConditions | None = None,
+    loaders: typing.Sequence[str] | None = None,
+    start_time: float | None = None,
 ) -> typing.Mapping[str, spec.LoadTestOutput]:
-    """run multiple load tests"""
+    """run multiple load tests
+
+    if parallel, local nodes are tested at the same time, each from its own
+    loader process, instead of one after another
+
+    if any stop condition is met after an attack, the remaining attacks of
+    that test are skipped and recorded as not run
+
+    if loaders are given, each attack is split across those remote hosts
+
+    if start_time is given, attacks start at scheduled times, as when this
+    process is one loader of a distributed test
+    """
+    import resource
+    import time
+
     # parse user_io
     if (node is None) == (nodes is None):
         raise Exception('must specify either node or nodes')
     if (test is None) == (tests is None):
         raise Exception('must specify either test or tests')
+    if parallel and (nodes is None or tests is not None):
+        raise Exception('parallel requires multiple nodes and a single test')
+    if loaders is not None:
+        if tests is not None:
+            raise Exception('loaders require a single test')
+        if parallel or stop_conditions is not None:
+            raise Exception('loaders not used with parallel or stop conditions')
+        if start_time is not None:
+            raise Exception('loaders schedule their own start times')
     if node is not None:
         node = user_io.parse_node(node)
     if nodes is not None:
@@ -42,13 +77,33 @@ def run_load_tests(
     }
 
     results = {}
+    t_start = time.time()
+    usage_start = resource.getrusage(resource.RUSAGE_CHILDREN)
+
+    # case: each attack split across multiple loaders
+    if loaders is not None and test is not None:
+        if node is not None:
+            nodes = {node['name']: node}
+        assert nodes is not None
+        for name, nd in nodes.items():
+            results[name] = distributed_load_tests.run_distributed_load_test(
+                node=nd,
+                test=test,
+                loaders=loaders,
+                verbose=verbose,
+                include_deep_output=include_deep_output,
+                engine=engine,
+            )
 
     # case: single node and single test
-    if node is not None and test is not None:
+    elif node is not None and test is not None:
         results[node['name']] = schedule_load_test(
             node=node,
             test=test,
             include_deep_output=include_deep_output,
+            engine=engine,
+            stop_conditions=stop_conditions,
+            start_time=start_time,
         )
 
     # case: single node and multiple tests
@@ -59,6 +114,9 @@ def run_load_tests(
                 verbose=verbose,
                 test=each_test,
                 include_deep_output=include_deep_output,
+                engine=engine,
+                stop_conditions=stop_conditions,
+                start_time=start_time,
             )
 
     # case: multiple nodes and single tests
@@ -69,6 +127,10 @@ def run_load_tests(
                 verbose=verbose,
                 test=test,
                 include_deep_output=include_deep_output,
+                engine=engine,
+                stop_conditions=stop_conditions,
+                start_time=start_time,
+                parallel=parallel,
             )
 
     # case: multiple nodes and multiple tests
@@ -80,6 +142,9 @@ def run_load_tests(
                     verbose=verbose,
                     test=test,
                     include_deep_output=include_deep_output,
+                    engine=engine,
+                    stop_conditions=stop_conditions,
+                    start_time=start_time,
                 )
 
     # case: invalid input
@@ -103,29 +168,75 @@ def run_load_tests(
                 import json
 
                 test_results: spec.SingleRunResultsPayload = json.load(f)
-                joined[name] = test_results['results'][name]
+                joined[name] = list(test_results['results'].values())[0]
         else:
             raise Exception('invalid result type')
 
+    # loader processes run concurrently, so check their combined usage
+    if parallel:
+        usage_end = resource.getrusage(resource.RUSAGE_CHILDREN)
+        _check_loader_utilization(
+            cpu_time=(usage_end.ru_utime - usage_start.ru_utime)
+            + (usage_end.ru_stime - usage_start.ru_stime),
+            wall_time=time.time() - t_start,
+            verbose=verbose,
+        )
+
     return joined
 
 
+def _check_loader_utilization(
+    *,
+    cpu_time: float,
+    wall_time: float,
+    verbose: bool | int,
+) -> float | None:
+    """warn if loader processes used most of the local cpu capacity
+
+    cpu_time includes the loader processes and the vegeta processes they
+    spawn, remote nodes use little local cpu so they do not trigger this
+    """
+    import os
+
+    n_cpus = os.cpu_count()
+    if n_cpus is None or wall_time <= 0:
+        return None
+    utilization = cpu_time / (wall_time * n_cpus)
+    if verbose and utilization > loader_monitoring.loader_saturation_cpu:
+        flood.user_io.print_timestamped(
+            'WARNING: local loader processes used '
+            + '{:.0%}'.format(utilization)
+            + ' of '
+            + str(n_cpus)
+            + ' cpus, results may be limited by the loader instead of the'
+            + ' nodes, consider testing fewer nodes at once'
+        )
+    return utilization
+
+
 def schedule_load_test(
     *,
     node: spec.NodeShorthand,
     test: spec.LoadTest | spec.TestGenerationParameters,
     verbose: bool | int = False,
     include_deep_output: typing.Sequence[spec.DeepOutput] | None = None,
+    engine: spec.LoadEngine | None = None,
+    parallel: bool = False,
+    stop_conditions: spec.StopConditions | None = None,
+    start_time: float | None = None,
     _pbar_kwargs: typing.Mapping[str, typing.Any] | None = None,
 ) -> (
     spec.LoadTestOutput
     | str
     | tuple[multiprocessing.Process, multiprocessing.Queue[str]]
 ):
-    """runs local tests synchronously, remote tests asynchronously"""
+    """runs local tests synchronously, remote tests asynchronously
+
+    if parallel, local tests also run asynchronously in a separate process
+    """
 
     node = user_io.parse_node(node)
-    if node['remote'] is not None:
+    if node['remote'] is not None or parallel:
         import multiprocessing
 
         queue: multiprocessing.Queue[str] = multiprocessing.Queue()
@@ -136,6 +247,9 @@ def schedule_load_test(
                 test=test,
                 verbose=verbose,
                 include_deep_output=include_deep_output,
+                engine=engine,
+                stop_conditions=stop_conditions,
+                start_time=start_time,
                 _pbar_kwargs=_pbar_kwargs,
                 _container=queue,
             ),
@@ -148,6 +262,9 @@ def schedule_load_test(
             test=test,
             verbose=verbose,
             include_deep_output=include_deep_output,
+            engine=engine,
+            stop_conditions=stop_conditions,
+            start_time=start_time,
             _pbar_kwargs=_pbar_kwargs,
         )
 
@@ -160,8 +277,15 @@ def run_load_test(
     _pbar_kwargs: typing.Mapping[str, typing.Any] | None = None,
     _container: multiprocessing.Queue[str] | None = None,
     include_deep_output: typing.Sequence[spec.DeepOutput] | None = None,
+    engine: spec.LoadEngine | None = None,
+    stop_conditions: spec.StopConditions | None = None,
+    start_time: float | None = None,
 ) -> spec.LoadTestOutput | str:
-    """run a load test against a single node"""
+    """run a load test against a single node
+
+    if start_time is given, attacks start at scheduled times for distributed
+    tests, see get_distributed_attack_start_times()
+    """
 
     # parse user_io
     node = user_io.parse_node(node)
@@ -174,6 +298,9 @@ def run_load_test(
             verbose=verbose,
             _pbar_kwargs=_pbar_kwargs,
             include_deep_output=include_deep_output,
+            engine=engine,
+            stop_conditions=stop_conditions,
+            start_time=start_time,
         )
     else:
         result = _run_load_test_remotely(
@@ -182,16 +309,33 @@ def run_load_test(
             verbose=verbose,
             _pbar_kwargs=_pbar_kwargs,
             include_deep_output=include_deep_output,
+            engine=engine,
+            stop_conditions=stop_conditions,
+            start_time=start_time,
         )
 
     if _container is not None:
         if not isinstance(result, str):
-            raise Exception('_container only supportted for remote testing')
+            result = _save_container_result(node=node, result=result)
         _container.put(result)
 
     return result
 
 
+def _save_container_result(
+    *, node: spec.Node, result: spec.LoadTestOutput
+) -> str:
+    """save result of local test run in a subprocess, return its path"""
+    import json
+    import tempfile
+
+    with tempfile.NamedTemporaryFile(
+        'w', prefix='flood__', suffix='.json', delete=False
+    ) as f:
+        json.dump({'results': {node['name']: result}}, f)
+    return f.name
+
+
 def _run_load_test_locally(
     *,
     node: spec.Node,
@@ -199,6 +343,9 @@ def _run_load_test_locally(
     verbose: bool | int = False,
     _pbar_kwargs: typing.Mapping[str, typing.Any] | None = None,
     include_deep_output: typing.Sequence[spec.DeepOutput] | None = None,
+    engine: spec.LoadEngine | None = None,
+    stop_conditions: spec.StopConditions | None = None,
+    start_time: float | None = None,
 ) -> spec.LoadTestOutput:
     """run a load test from local node"""
 
@@ -219,34 +366,218 @@ def _run_load_test_locally(
         **_pbar_kwargs,
     )
 
+    # head-relative blocks are regenerated to follow this node's chain head
     use_test: spec.LoadTest
-    if 'attacks' in test:
+    test_parameters = test.get('test_parameters', {})  # type: ignore
+    if 'attacks' not in test:
+        use_test = flood.generate_test(**test, rpc_url=node['url'])
+    elif test_parameters.get('blocks') is not None:
+        use_test = flood.generate_test(**test_parameters, rpc_url=node['url'])
+    else:
         use_test = test  # type: ignore
+
+    # get scheduled start of each attack for distributed tests
+    if start_time is not None:
+        attack_start_times: typing.Sequence[float | None] = (
+            distributed_load_tests.get_distributed_attack_start_times(
+                start_time=start_time,
+                durations=[
+                    attack['duration'] for attack in use_test['attacks']
+                ],
+            )
+        )
     else:
-        use_test = flood.generate_test(**test)
+        attack_start_times = [None] * len(use_test['attacks'])
 
     # perform tests
     results = []
-    for attack in tqdm.tqdm(use_test['attacks'], **tqdm_kwargs):
-        if verbose:
-            flood.user_io.print_timestamped(
-                'Running attack at rate = ' + str(attack['rate']) + ' rps'
+    stop_reason = None
+    for attack, attack_start_time in tqdm.tqdm(
+        zip(use_test['attacks'], attack_start_times), **tqdm_kwargs
+    ):
+        concurrency = attack.get('concurrency')
+        if stop_reason is not None:
+            results.append(
+                _create_not_run_datum(
+                    attack=attack, include_deep_output=include_deep_output
+                )
             )
+            continue
+        if attack_start_time is not None:
+            _wait_for_attack_start(attack_start_time, verbose=verbose)
+        if verbose:
+            if concurrency is not None:
+                flood.user_io.print_timestamped(
+                    'Running attack at concurrency = '
+                    + str(concurrency)
+                    + ' workers'
+                )
+            else:
+                flood.user_io.print_timestamped(
+                    'Running attack at rate = ' + str(attack['rate']) + ' rps'
+                )
 
         result = vegeta.run_vegeta_attack(
             url=node['url'],
             calls=attack['calls'],
             duration=attack['duration'],
             rate=attack['rate'],
+            concurrency=concurrency,
+            think_time=attack.get('think_time'),
+            offsets=attack.get('offsets'),
             vegeta_args=attack['vegeta_args'],
             verbose=verbose >= 2,
             include_deep_output=include_deep_output,
+            engine=engine,
+            metrics_url=node.get('metrics_url'),
+            count_timeouts=(
+                stop_conditions is not None
+                and stop_conditions.get('max_consecutive_timeouts')
+                is not None
+            ),
         )
         results.append(result)
         if verbose >= 2:
             print()
 
-    # format output
+        # check whether remaining attacks should be skipped
+        if stop_conditions is not None:
+            stop_reason = _get_stop_reason(result, stop_conditions)
+            n_remaining = len(use_test['attacks']) - len(results)
+            if verbose and stop_reason is not None and n_remaining > 0:
+                flood.user_io.print_timestamped(
+                    'Stopping test for '
+                    + node['name']
+                    + ', '
+                    + stop_reason
+                    + ', skipping '
+                    + str(n_remaining)
+                    + ' remaining attacks'
+                )
+
+    return _format_load_test_output(
+        results=results, include_deep_output=include_deep_output
+    )
+
+
+def _wait_for_attack_start(start_time: float, verbose: bool | int) -> None:
+    import time
+
+    delay = start_time - time.time()
+    if delay > 0:
+        time.sleep(delay)
+    elif verbose:
+        flood.user_io.print_timestamped(
+            'WARNING: attack started '
+            + '{:.1f}'.format(-delay)
+            + 's after its scheduled time, loaders may be out of sync'
+        )
+
+
+def _get_stop_reason(
+    result: spec.LoadTestOutputDatum,
+    stop_conditions: spec.StopConditions,
+) -> str | None:
+    """get which stop condition an attack met, or None if none were met"""
+    min_success = stop_conditions.get('min_success')
+    max_p99 = stop_conditions.get('max_p99')
+    max_timeouts = stop_conditions.get('max_consecutive_timeouts')
+    success = result['success']
+    p99 = result['p99']
+    if min_success is not None and success is not None:
+        if success < min_success:
+            return (
+                'success rate '
+                + '{:.1%}'.format(success)
+                + ' below '
+                + '{:.1%}'.format(min_success)
+            )
+    if max_p99 is not None and p99 is not None:
+        if p99 > max_p99:
+            return (
+                'p99 latency '
+                + '{:.3f}'.format(p99)
+                + 's above '
+                + str(max_p99)
+                + 's'
+            )
+    if max_timeouts is not None:
+        n_timeouts = result.get('max_consecutive_timeouts')
+        if n_timeouts is not None and n_timeouts >= max_timeouts:
+            return str(n_timeouts) + ' consecutive timeouts'
+    return None
+
+
+def _create_not_run_datum(
+    attack: spec.VegetaAttack,
+    include_deep_output: typing.Sequence[spec.DeepOutput] | None,
+) -> spec.LoadTestOutputDatum:
+    """create placeholder output for attack skipped by a stop condition"""
+    concurrency = attack.get('concurrency')
+    if concurrency is not None:
+        target_rate = None
+    else:
+        target_rate = attack['rate']
+
+    deep_metrics = None
+    if include_deep_output is not None and 'metrics' in include_deep_output:
+        categories: list[spec.ResponseCategory] = [
+            'all',
+            'successful',
+            'failed',
+        ]
+        deep_metrics = {
+            category: deep_utils._get_empty_sample_metrics(
+                target_rate=target_rate,
+                target_duration=attack['duration'],
+                target_concurrency=concurrency,
+            )
+            for category in categories
+        }
+
+    return {
+        'target_rate': target_rate,
+        'target_concurrency': concurrency,
+        'actual_rate': None,
+        'target_duration': attack['duration'],
+        'actual_duration': None,
+        'requests': 0,
+        'throughput': None,
+        'success': None,
+        'min': None,
+        'mean': None,
+        'p50': None,
+        'p90': None,
+        'p95': None,
+        'p99': None,
+        'max': None,
+        'status_codes': {},
+        'errors': [],
+        'first_request_timestamp': None,
+        'last_request_timestamp': None,
+        'last_response_timestamp': None,
+        'final_wait_time': None,
+        'latency_histogram': None,
+        'max_consecutive_timeouts': None,
+        'not_run': True,
+        'loader_metrics': None,
+        'node_metrics': None,
+        'host_metrics': None,
+        'deep_raw_output': None,
+        'deep_metrics': deep_metrics,
+        'deep_rpc_error_pairs': None,
+        'deep_method_metrics': None,
+        'deep_time_series': None,
+        'deep_slowest_calls': None,
+        'deep_latency_breakdowns': None,
+    }
+
+
+def _format_load_test_output(
+    results: typing.Sequence[spec.LoadTestOutputDatum],
+    include_deep_output: typing.Sequence[spec.DeepOutput] | None,
+) -> spec.LoadTestOutput:
+    """convert per-attack outputs into a single column-wise output"""
     output_data: spec.LoadTestOutput = _list_of_maps_to_map_of_lists(results)  # type: ignore # noqa: E501
 
     # format deep output
@@ -270,6 +601,38 @@ def _run_load_test_locally(
             )
         output_data['deep_metrics'] = deep_metrics  # type: ignore
 
+        # methods can be missing from attacks with few calls
+        methods = sorted(
+            {
+                method
+                for result in results
+                if result['deep_method_metrics'] is not None
+                for method in result['deep_method_metrics'].keys()
+            }
+        )
+        if len(methods) > 0:
+            deep_method_metrics = {}
+            for method in methods:
+                method_results = []
+                for result in results:
+                    method_metrics = result['deep_method_metrics']
+                    if method_metrics is not None and method in method_metrics:
+                        method_results.append(method_metrics[method])
+                    else:
+                        method_results.append(
+                            deep_utils._get_empty_sample_metrics(
+                                target_rate=result['target_rate'],
+                                target_duration=result['target_duration'],
+                                target_concurrency=result['target_concurrency'],
+                            )
+                        )
+                deep_method_metrics[method] = _list_of_maps_to_map_of_lists(
+                    method_results
+                )
+            output_data['deep_method_metrics'] = deep_method_metrics  # type: ignore # noqa: E501
+        else:
+            output_data['deep_method_metrics'] = None
+
     return output_data
 
 
@@ -287,6 +650,9 @@ def _run_load_test_remotely(
     verbose: bool | int = False,
     _pbar_kwargs: typing.Mapping[str, typing.Any] | None = None,
     include_deep_output: typing.Sequence[spec.DeepOutput] | None = None,
+    engine: spec.LoadEngine | None = None,
+    stop_conditions: spec.StopConditions | None = None,
+    start_time: float | None = None,
 ) -> str:
     """run a load test from local node"""
 
@@ -300,6 +666,12 @@ def _run_load_test_remotely(
     remote = node['remote']
     if remote is None:
         raise Exception('not a remote node')
+    test_parameters: flood.TestGenerationParameters
+    if 'test_parameters' in test:
+        test_parameters = test['test_parameters']  # type: ignore
+    else:
+        test_parameters = test  # type: ignore
+    replay_path = test_parameters.get('replay_path')
 
     # check remote installation
     local_installation = flood.get_local_installation()
@@ -312,7 +684,12 @@ def _run_load_test_remotely(
             'could not find flood installation on remote host ' + node['name']
         )
         sys.exit()
-    if remote_vegeta_path is None:
+    uses_vegeta = engine == 'vegeta' or (
+        engine is None
+        and not native.is_websocket_url(node['url'])
+        and replay_path is None
+    )
+    if remote_vegeta_path is None and uses_vegeta:
         raise Exception(
             'could not find vegeta installation on remote host ' + node['name']
         )
@@ -329,11 +706,17 @@ def _run_load_test_remotely(
     job_id = str(uuid.uuid4())
     tempdir = '/tmp/flood__' + job_id
     os.makedirs(tempdir)
-    test_parameters: flood.TestGenerationParameters
-    if 'test_parameters' in test:
-        test_parameters = test['test_parameters']  # type: ignore
-    else:
-        test_parameters = test
+
+    # copy replayed traffic log so that remote can regenerate the test
+    if replay_path is not None:
+        import shutil
+
+        remote_replay_path = os.path.join(tempdir, 'traffic.jsonl')
+        shutil.copy(replay_path, remote_replay_path)
+        test_parameters = dict(  # type: ignore
+            test_parameters, replay_path=remote_replay_path
+        )
+
     flood.runners.single_runner.single_runner_io._save_single_run_test(
         test_name='',
         test_parameters=test_parameters,
@@ -368,6 +751,25 @@ def _run_load_test_remotely(
             extra_kwargs += ' --save-raw-output'
         if 'metrics' in include_deep_output:
             extra_kwargs += ' --deep-check'
+    if engine is not None:
+        extra_kwargs += ' --engine ' + engine
+    if start_time is not None:
+        extra_kwargs += ' --start-at ' + str(start_time)
+    if node.get('metrics_url') is not None:
+        extra_kwargs += (
+            ' --node-metrics ' + node['name'] + '=' + str(node['metrics_url'])
+        )
+    if stop_conditions is not None:
+        if stop_conditions.get('min_success') is not None:
+            extra_kwargs += ' --stop-success ' + str(
+                stop_conditions['min_success']
+            )
+        if stop_conditions.get('max_p99') is not None:
+            extra_kwargs += ' --stop-p99 ' + str(stop_conditions['max_p99'])
+        if stop_conditions.get('max_consecutive_timeouts') is not None:
+            extra_kwargs += ' --stop-timeouts ' + str(
+                stop_conditions['max_consecutive_timeouts']
+            )
     cmd = cmd_template.format(
         host=remote,
         name=node['name'],
@@ -377,7 +779,12 @@ def _run_load_test_remotely(
         extra_kwargs=extra_kwargs.lstrip(),
     )
     cmd = cmd.strip()
-    subprocess.check_output(cmd.split(' '), stderr=subprocess.DEVNULL)
+    sampler = host_monitoring.RemoteHostSampler(remote)
+    sampler.start()
+    try:
+        subprocess.check_output(cmd.split(' '), stderr=subprocess.DEVNULL)
+    finally:
+        host_samples = sampler.stop()
 
     # retrieve benchmark results
     if verbose:
@@ -385,6 +792,83 @@ def _run_load_test_remotely(
     results_path = os.path.join(tempdir, 'results.json')
     cmd = 'rsync ' + remote + ':' + results_path + ' ' + results_path
     subprocess.call(cmd.split(' '), stderr=subprocess.DEVNULL)
+    _add_remote_host_metrics(
+        node_name=node['name'],
+        results_path=results_path,
+        samples=host_samples,
+        interval=sampler.interval,
+    )
+    if include_deep_output is not None and 'raw' in include_deep_output:
+        _retrieve_remote_raw_output(
+            remote=remote,
+            node_name=node['name'],
+            tempdir=tempdir,
+            results_path=results_path,
+        )
 
     return results_path
 
+
+def _add_remote_host_metrics(
+    *,
+    node_name: str,
+    results_path: str,
+    samples: typing.Sequence[tuple[float, typing.Mapping[str, float]]],
+    interval: float,
+) -> None:
+    """split host samples of remote test into its attacks and embed them"""
+    import json
+
+    with open(results_path, 'r') as f:
+        payload: spec.SingleRunResultsPayload = json.load(f)
+    result = payload['results'][node_name]
+    attack_times: list[tuple[float, float] | None] = []
+    for start, end in zip(
+        result['first_request_timestamp'], result['last_response_timestamp']
+    ):
+        if start is None or end is None:
+            attack_times.append(None)
+        else:
+            attack_times.append(
+                (
+                    native._parse_timestamp_ns(start) / 1e9,
+                    native._parse_timestamp_ns(end) / 1e9,
+                )
+            )
+    result['host_metrics'] = host_monitoring.split_host_samples(
+        samples, interval=interval, attack_times=attack_times
+    )
+    with open(results_path, 'w') as f:
+        json.dump(payload, f)
+
+
+def _retrieve_remote_raw_output(
+    *,
+    remote: str,
+    node_name: str,
+    tempdir: str,
+    results_path: str,
+) -> None:
+    """retrieve parquet raw output of remote test and embed it in results"""
+    import json
+    import os
+    import subprocess
+
+    single_runner_io = flood.runners.single_runner.single_runner_io
+    raw_output_path = single_runner_io.get_single_run_raw_output_path(
+        output_dir=tempdir, node_name=node_name
+    )
+    os.makedirs(os.path.dirname(raw_output_path), exist_ok=True)
+    cmd = 'rsync ' + remote + ':' + raw_output_path + ' ' + raw_output_path
+    subprocess.call(cmd.split(' '), stderr=subprocess.DEVNULL)
+
+    with open(results_path, 'r') as f:
+        payload: spec.SingleRunResultsPayload = json.load(f)
+    result = payload['results'][node_name]
+    df = raw_outputs.load_raw_outputs(raw_output_path)
+    result['deep_raw_output'] = raw_outputs.convert_dataframe_to_raw_outputs(
+        df, n_attacks=len(result['target_duration'])
+    )
+    with open(results_path, 'w') as f:
+        json.dump(payload, f)
+
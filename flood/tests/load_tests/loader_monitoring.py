@@ -0,0 +1,182 @@
+"""sample resource usage of the load-generating host during attacks
+
+if the loader is saturated, actual_rate and latencies may reflect limits of
+the loader instead of limits of the node
+
+cpu, memory, and sockets are read from /proc and are None on other platforms,
+scheduling lag is how late the sampling thread wakes up, which grows when
+the loader's cpus or python's event loop are overloaded
+"""
+from __future__ import annotations
+
+import typing
+
+from ... import spec
+
+
+default_loader_sample_interval = 0.25
+
+# thresholds above which the loader is considered saturated
+loader_saturation_cpu = 0.9
+loader_saturation_memory = 0.95
+loader_saturation_scheduling_lag = 0.05
+
+
+class LoaderMonitor:
+    """sample resource usage of loader in a background thread"""
+
+    def __init__(self, interval: float | None = None) -> None:
+        import threading
+
+        if interval is None:
+            interval = default_loader_sample_interval
+        self.interval = interval
+        self._cpu: list[float] = []
+        self._memory: list[float] = []
+        self._sockets: list[int] = []
+        self._lags: list[float] = []
+        self._stop = threading.Event()
+        self._thread = threading.Thread(target=self._run, daemon=True)
+
+    def start(self) -> None:
+        self._thread.start()
+
+    def stop(self) -> spec.LoaderMetrics:
+        self._stop.set()
+        self._thread.join()
+        return {
+            'interval': self.interval,
+            'n_samples': len(self._lags),
+            'cpu_mean': _mean(self._cpu),
+            'cpu_max': _max(self._cpu),
+            'memory_max': _max(self._memory),
+            'sockets_max': _max(self._sockets),
+            'scheduling_lag_mean': _mean(self._lags),
+            'scheduling_lag_max': _max(self._lags),
+        }
+
+    def _run(self) -> None:
+        import time
+
+        previous_cpu_times = _read_cpu_times()
+        t_previous = time.perf_counter()
+        while not self._stop.wait(self.interval):
+            t_now = time.perf_counter()
+            self._lags.append(max(t_now - t_previous - self.interval, 0.0))
+            t_previous = t_now
+
+            cpu_times = _read_cpu_times()
+            if cpu_times is not None and previous_cpu_times is not None:
+                busy = cpu_times[0] - previous_cpu_times[0]
+                total = cpu_times[1] - previous_cpu_times[1]
+                if total > 0:
+                    self._cpu.append(busy / total)
+            previous_cpu_times = cpu_times
+
+            memory = _read_memory_usage()
+            if memory is not None:
+                self._memory.append(memory)
+            sockets = _read_open_sockets()
+            if sockets is not None:
+                self._sockets.append(sockets)
+
+
+def get_loader_saturation_reasons(
+    loader_metrics: spec.LoaderMetrics | None,
+) -> typing.Sequence[str]:
+    """get reasons that loader was saturated, empty if it was not"""
+    if loader_metrics is None:
+        return []
+    reasons = []
+    cpu_mean = loader_metrics['cpu_mean']
+    if cpu_mean is not None and cpu_mean > loader_saturation_cpu:
+        reasons.append('cpu ' + '{:.0%}'.format(cpu_mean))
+    memory_max = loader_metrics['memory_max']
+    if memory_max is not None and memory_max > loader_saturation_memory:
+        reasons.append('memory ' + '{:.0%}'.format(memory_max))
+    lag = loader_metrics['scheduling_lag_mean']
+    if lag is not None and lag > loader_saturation_scheduling_lag:
+        reasons.append('scheduling lag ' + '{:.3f}'.format(lag) + 's')
+    return reasons
+
+
+def merge_loader_metrics(
+    loader_metrics: typing.Sequence[spec.LoaderMetrics | None],
+) -> spec.LoaderMetrics | None:
+    """combine metrics of multiple loaders, keeping the most saturated"""
+    use_metrics = [metrics for metrics in loader_metrics if metrics is not None]
+    if len(use_metrics) == 0:
+        return None
+    merged = dict(use_metrics[0])
+    for metrics in use_metrics[1:]:
+        for key, value in metrics.items():
+            if key in ['interval', 'n_samples']:
+                continue
+            if merged[key] is None or (
+                value is not None and value > merged[key]  # type: ignore
+            ):
+                merged[key] = value
+    return merged  # type: ignore
+
+
+def _read_cpu_times() -> tuple[float, float] | None:
+    """read busy and total cpu time of all cpus"""
+    try:
+        with open('/proc/stat', 'r') as f:
+            line = f.readline()
+    except OSError:
+        return None
+    return _parse_cpu_times(line)
+
+
+def _parse_cpu_times(line: str) -> tuple[float, float]:
+    """parse busy and total cpu time from first line of /proc/stat"""
+    times = [float(value) for value in line.split()[1:]]
+    idle = times[3] + times[4]
+    total = sum(times[:8])
+    return total - idle, total
+
+
+def _read_memory_usage() -> float | None:
+    """read fraction of memory in use"""
+    try:
+        with open('/proc/meminfo', 'r') as f:
+            lines = f.readlines()
+    except OSError:
+        return None
+    return _parse_memory_usage(lines)
+
+
+def _parse_memory_usage(lines: typing.Sequence[str]) -> float | None:
+    """parse fraction of memory in use from lines of /proc/meminfo"""
+    values = {}
+    for line in lines:
+        key, _, value = line.partition(':')
+        values[key] = float(value.split()[0])
+    if 'MemTotal' not in values or 'MemAvailable' not in values:
+        return None
+    return 1 - values['MemAvailable'] / values['MemTotal']
+
+
+def _read_open_sockets() -> int | None:
+    try:
+        with open('/proc/net/sockstat', 'r') as f:
+            line = f.readline()
+    except OSError:
+        return None
+    fields = line.split()
+    if fields[:2] != ['sockets:', 'used']:
+        return None
+    return int(fields[2])
+
+
+def _mean(values: typing.Sequence[float]) -> float | None:
+    if len(values) == 0:
+        return None
+    return sum(values) / len(values)
+
+
+def _max(values: typing.Sequence[typing.Any]) -> typing.Any:
+    if len(values) == 0:
+        return None
+    return max(values)
@@ -0,0 +1,568 @@
+"""pure python load engine, an alternative to the vegeta subprocess
+
+raw output is encoded as vegeta's json result format (one result per line) so
+that it can be consumed by the same deep check and serde utilities as vegeta
+"""
+from __future__ import annotations
+
+import typing
+
+from ... import spec
+
+if typing.TYPE_CHECKING:
+    import asyncio
+
+    import aiohttp
+
+
+default_timeout = 30
+default_max_connections = 10_000
+default_ws_connections = 16
+
+
+def run_native_attack(
+    *,
+    url: str,
+    rate: int,
+    duration: int,
+    calls: typing.Sequence[typing.Any],
+    concurrency: int | None = None,
+    think_time: float | None = None,
+    offsets: typing.Sequence[float] | None = None,
+    max_connections: int | None = None,
+    timeout: float | None = None,
+    verbose: bool = False,
+) -> bytes:
+    """run an attack, return raw output as vegeta json lines
+
+    attacks are open-loop at a fixed rate, unless concurrency is given, in
+    which case each worker waits think_time after each response before sending
+    its next request, or offsets is given, in which case each call is sent at
+    its offset in seconds from the start of the attack
+
+    ws:// and wss:// urls are attacked over a pool of persistent websocket
+    connections, where max_connections is the size of the pool
+    """
+    import asyncio
+
+    if verbose:
+        print('running native attack...')
+        print('- url:', url)
+        if concurrency is not None:
+            print('- concurrency:', concurrency)
+            print('- think time:', think_time)
+        elif offsets is not None:
+            print('- replayed calls:', len(offsets))
+        else:
+            print('- rate:', rate)
+        print('- duration:', duration)
+
+    if len(calls) == 0:
+        raise Exception('must specify at least one call')
+    if timeout is None:
+        timeout = default_timeout
+
+    if is_websocket_url(url):
+        if max_connections is None:
+            max_connections = default_ws_connections
+        coroutine = _async_native_ws_attack(
+            url=url,
+            rate=rate,
+            duration=duration,
+            calls=calls,
+            concurrency=concurrency,
+            think_time=think_time,
+            offsets=offsets,
+            n_connections=max_connections,
+            timeout=timeout,
+        )
+    else:
+        if max_connections is None:
+            max_connections = default_max_connections
+        coroutine = _async_native_http_attack(
+            url=url,
+            rate=rate,
+            duration=duration,
+            calls=calls,
+            concurrency=concurrency,
+            think_time=think_time,
+            offsets=offsets,
+            max_connections=max_connections,
+            timeout=timeout,
+        )
+    results = asyncio.run(coroutine)
+    return _encode_native_results(results)
+
+
+def is_websocket_url(url: str) -> bool:
+    return url.startswith('ws://') or url.startswith('wss://')
+
+
+async def _run_attack(
+    *,
+    rate: int,
+    duration: int,
+    concurrency: int | None,
+    think_time: float | None,
+    offsets: typing.Sequence[float] | None,
+    send: typing.Callable[
+        [int], typing.Awaitable[typing.Mapping[str, typing.Any]]
+    ],
+) -> typing.Sequence[typing.Mapping[str, typing.Any]]:
+    if concurrency is not None:
+        return await _run_closed_loop(
+            concurrency=concurrency,
+            duration=duration,
+            think_time=think_time,
+            send=send,
+        )
+    elif offsets is not None:
+        return await _run_schedule(
+            rate=rate, n_requests=len(offsets), send=send, offsets=offsets
+        )
+    else:
+        return await _run_schedule(
+            rate=rate, n_requests=rate * duration, send=send
+        )
+
+
+async def _run_schedule(
+    *,
+    rate: int,
+    n_requests: int,
+    send: typing.Callable[
+        [int], typing.Awaitable[typing.Mapping[str, typing.Any]]
+    ],
+    offsets: typing.Sequence[float] | None = None,
+) -> typing.Sequence[typing.Mapping[str, typing.Any]]:
+    """dispatch requests at a fixed rate without waiting for responses
+
+    if offsets are given, each request is sent at its offset in seconds
+    """
+    import asyncio
+    import time
+
+    tasks = []
+    t_start = time.perf_counter()
+    for seq in range(n_requests):
+        if offsets is not None:
+            t_target = t_start + offsets[seq]
+        else:
+            t_target = t_start + seq / rate
+        delay = t_target - time.perf_counter()
+        if delay > 0:
+            await asyncio.sleep(delay)
+        tasks.append(asyncio.create_task(send(seq)))
+    return await asyncio.gather(*tasks)
+
+
+async def _run_closed_loop(
+    *,
+    concurrency: int,
+    duration: int,
+    think_time: float | None,
+    send: typing.Callable[
+        [int], typing.Awaitable[typing.Mapping[str, typing.Any]]
+    ],
+) -> typing.Sequence[typing.Mapping[str, typing.Any]]:
+    """each worker sends its next request once its previous one completes"""
+    import asyncio
+    import itertools
+    import time
+
+    if concurrency < 1:
+        raise Exception('concurrency must be at least 1')
+
+    counter = itertools.count()
+    results: list[typing.Mapping[str, typing.Any]] = []
+    t_end = time.perf_counter() + duration
+
+    async def worker() -> None:
+        while time.perf_counter() < t_end:
+            results.append(await send(next(counter)))
+            if think_time is not None and think_time > 0:
+                await asyncio.sleep(think_time)
+
+    await asyncio.gather(*[worker() for _ in range(concurrency)])
+    return sorted(results, key=lambda result: result['seq'])
+
+
+#
+# # http
+#
+
+
+async def _async_native_http_attack(
+    *,
+    url: str,
+    rate: int,
+    duration: int,
+    calls: typing.Sequence[typing.Any],
+    concurrency: int | None,
+    think_time: float | None,
+    offsets: typing.Sequence[float] | None,
+    max_connections: int,
+    timeout: float,
+) -> typing.Sequence[typing.Mapping[str, typing.Any]]:
+    import json
+    import aiohttp
+
+    connector = aiohttp.TCPConnector(limit=max_connections)
+    client_timeout = aiohttp.ClientTimeout(total=timeout)
+    async with aiohttp.ClientSession(
+        connector=connector, timeout=client_timeout
+    ) as session:
+        # calls are encoded as they are sent, and cycled like vegeta does
+        return await _run_attack(
+            rate=rate,
+            duration=duration,
+            concurrency=concurrency,
+            think_time=think_time,
+            offsets=offsets,
+            send=lambda seq: _send_http_request(
+                session=session,
+                url=url,
+                body=json.dumps(calls[seq % len(calls)]).encode(),
+                seq=seq,
+            ),
+        )
+
+
+async def _send_http_request(
+    *,
+    session: aiohttp.ClientSession,
+    url: str,
+    body: bytes,
+    seq: int,
+) -> typing.Mapping[str, typing.Any]:
+    import time
+
+    headers = {'Content-Type': 'application/json'}
+    timestamp = time.time_ns()
+    t_start = time.perf_counter_ns()
+    code = 0
+    response_body = b''
+    error = ''
+    try:
+        async with session.post(url, data=body, headers=headers) as response:
+            response_body = await response.read()
+            code = response.status
+            if code < 200 or code >= 400:
+                error = str(code) + ' ' + str(response.reason)
+    except Exception as e:
+        error = 'Post "' + url + '": ' + (str(e) or type(e).__name__)
+    latency = time.perf_counter_ns() - t_start
+
+    return {
+        'seq': seq,
+        'code': code,
+        'timestamp': timestamp,
+        'latency': latency,
+        'bytes_out': len(body),
+        'bytes_in': len(response_body),
+        'error': error,
+        'body': response_body,
+        'method': 'POST',
+        'url': url,
+    }
+
+
+#
+# # websocket
+#
+
+
+async def _async_native_ws_attack(
+    *,
+    url: str,
+    rate: int,
+    duration: int,
+    calls: typing.Sequence[typing.Any],
+    concurrency: int | None,
+    think_time: float | None,
+    offsets: typing.Sequence[float] | None,
+    n_connections: int,
+    timeout: float,
+) -> typing.Sequence[typing.Mapping[str, typing.Any]]:
+    """requests are assigned to connections round robin
+
+    the id of each request is replaced by its sequence number so that responses
+    can be multiplexed over each connection, responses are matched back by id
+
+    calls in a batch request get ids of the form '<sequence number>.<index>'
+    """
+    import asyncio
+    import aiohttp
+
+    async with aiohttp.ClientSession() as session:
+        connections = []
+        readers = []
+        pendings: list[dict[typing.Any, asyncio.Future[bytes]]] = []
+        for _ in range(n_connections):
+            ws = await session.ws_connect(url, max_msg_size=0)
+            pending: dict[typing.Any, asyncio.Future[bytes]] = {}
+            connections.append(ws)
+            pendings.append(pending)
+            readers.append(
+                asyncio.create_task(_read_ws_responses(ws, pending))
+            )
+
+        try:
+            return await _run_attack(
+                rate=rate,
+                duration=duration,
+                concurrency=concurrency,
+                think_time=think_time,
+                offsets=offsets,
+                send=lambda seq: _send_ws_request(
+                    ws=connections[seq % n_connections],
+                    pending=pendings[seq % n_connections],
+                    call=calls[seq % len(calls)],
+                    url=url,
+                    seq=seq,
+                    timeout=timeout,
+                ),
+            )
+        finally:
+            for reader in readers:
+                reader.cancel()
+            for ws in connections:
+                await ws.close()
+
+
+async def _read_ws_responses(
+    ws: aiohttp.ClientWebSocketResponse,
+    pending: typing.MutableMapping[typing.Any, asyncio.Future[bytes]],
+) -> None:
+    """resolve pending requests of a connection as their responses arrive"""
+    import json
+    import aiohttp
+
+    try:
+        async for message in ws:
+            if message.type == aiohttp.WSMsgType.TEXT:
+                data = message.data.encode()
+            elif message.type == aiohttp.WSMsgType.BINARY:
+                data = message.data
+            else:
+                break
+            try:
+                decoded = json.loads(data)
+                if isinstance(decoded, list):
+                    batch_id = str(decoded[0].get('id'))
+                    response_id = int(batch_id.split('.')[0])
+                else:
+                    response_id = decoded.get('id')
+            except Exception:
+                continue
+            future = pending.pop(response_id, None)
+            if future is not None and not future.done():
+                future.set_result(data)
+    finally:
+        for future in pending.values():
+            if not future.done():
+                future.set_exception(Exception('websocket connection closed'))
+        pending.clear()
+
+
+async def _send_ws_request(
+    *,
+    ws: aiohttp.ClientWebSocketResponse,
+    pending: typing.MutableMapping[typing.Any, asyncio.Future[bytes]],
+    call: typing.Any,
+    url: str,
+    seq: int,
+    timeout: float,
+) -> typing.Mapping[str, typing.Any]:
+    """websocket responses are recorded with status code 200 if received"""
+    import asyncio
+    import json
+    import time
+
+    payload: typing.Any
+    if isinstance(call, list):
+        payload = [
+            dict(item, id=str(seq) + '.' + str(i))
+            for i, item in enumerate(call)
+        ]
+    else:
+        payload = dict(call, id=seq)
+    body = json.dumps(payload).encode()
+    future: asyncio.Future[bytes] = asyncio.get_running_loop().create_future()
+    pending[seq] = future
+
+    timestamp = time.time_ns()
+    t_start = time.perf_counter_ns()
+    code = 0
+    response_body = b''
+    error = ''
+    try:
+        await ws.send_str(body.decode())
+        response_body = await asyncio.wait_for(future, timeout=timeout)
+        code = 200
+    except asyncio.TimeoutError:
+        pending.pop(seq, None)
+        error = 'Send "' + url + '": timeout awaiting response'
+    except Exception as e:
+        pending.pop(seq, None)
+        error = 'Send "' + url + '": ' + (str(e) or type(e).__name__)
+    latency = time.perf_counter_ns() - t_start
+
+    return {
+        'seq': seq,
+        'code': code,
+        'timestamp': timestamp,
+        'latency': latency,
+        'bytes_out': len(body),
+        'bytes_in': len(response_body),
+        'error': error,
+        'body': response_body,
+        'method': 'WS',
+        'url': url,
+    }
+
+
+#
+# # encoding
+#
+
+
+def _encode_native_results(
+    results: typing.Sequence[typing.Mapping[str, typing.Any]]
+) -> bytes:
+    import base64
+    import json
+
+    lines = []
+    for result in results:
+        encoded = {
+            'attack': '',
+            'seq': result['seq'],
+            'code': result['code'],
+            'timestamp': _format_timestamp_ns(result['timestamp']),
+            'latency': result['latency'],
+            'bytes_out': result['bytes_out'],
+            'bytes_in': result['bytes_in'],
+            'error': result['error'],
+            'body': base64.b64encode(result['body']).decode(),
+            'method': result['method'],
+            'url': result['url'],
+            'headers': None,
+        }
+        if 'rpc_method' in result:
+            encoded['rpc_method'] = result['rpc_method']
+        lines.append(json.dumps(encoded))
+    return ('\n'.join(lines) + '\n').encode()
+
+
+def decode_native_results(
+    raw_output: bytes,
+) -> typing.Sequence[typing.Mapping[str, typing.Any]]:
+    """decode json lines raw output, timestamps as int nanoseconds"""
+    import json
+
+    results = []
+    for line in raw_output.decode().splitlines():
+        if line.strip() == '':
+            continue
+        result = json.loads(line)
+        result['timestamp'] = _parse_timestamp_ns(result['timestamp'])
+        results.append(result)
+    return results
+
+
+def _format_timestamp_ns(timestamp: int) -> str:
+    """format integer unix nanoseconds as RFC3339 with nanosecond precision"""
+    import datetime
+
+    seconds, nanoseconds = divmod(timestamp, 1_000_000_000)
+    dt = datetime.datetime.fromtimestamp(seconds, tz=datetime.timezone.utc)
+    return dt.strftime('%Y-%m-%dT%H:%M:%S') + '.%09dZ' % nanoseconds
+
+
+def _parse_timestamp_ns(timestamp: str) -> int:
+    """parse RFC3339 timestamp into integer unix nanoseconds"""
+    import datetime
+
+    if timestamp.endswith('Z'):
+        timestamp = timestamp[:-1] + '+00:00'
+    head, offset = timestamp[:19], timestamp[19:]
+    nanoseconds = 0
+    if offset.startswith('.'):
+        fraction = ''
+        for char in offset[1:]:
+            if not char.isdigit():
+                break
+            fraction += char
+        offset = offset[len(fraction) + 1 :]
+        nanoseconds = int(fraction.ljust(9, '0')[:9])
+    dt = datetime.datetime.fromisoformat(head + offset)
+    return int(dt.timestamp()) * 1_000_000_000 + nanoseconds
+
+
+#
+# # reports
+#
+
+
+def create_native_report(raw_output: bytes) -> spec.RawLoadTestOutputDatum:
+    """compute the equivalent of `vegeta report -type json` in python"""
+    import numpy as np
+
+    results = decode_native_results(raw_output)
+    if len(results) == 0:
+        raise Exception('no results in raw output')
+
+    latencies = np.array([result['latency'] for result in results])
+    timestamps = [result['timestamp'] for result in results]
+    earliest = min(timestamps)
+    latest = max(timestamps)
+    end = max(result['timestamp'] + result['latency'] for result in results)
+    duration = latest - earliest
+    wait = end - latest
+    n_requests = len(results)
+    n_success = sum(1 for result in results if 200 <= result['code'] < 400)
+
+    status_codes: dict[str, int] = {}
+    for result in results:
+        key = str(result['code'])
+        status_codes[key] = status_codes.get(key, 0) + 1
+    errors = sorted({result['error'] for result in results if result['error']})
+
+    if duration > 0:
+        rate = n_requests / duration * 1e9
+    else:
+        rate = 0.0
+    if duration + wait > 0:
+        throughput = n_success / (duration + wait) * 1e9
+    else:
+        throughput = 0.0
+
+    bytes_in = sum(result['bytes_in'] for result in results)
+    bytes_out = sum(result['bytes_out'] for result in results)
+
+    return {
+        'latencies': {
+            'total': int(latencies.sum()),
+            'mean': int(latencies.mean()),
+            '50th': int(np.quantile(latencies, 0.50)),
+            '90th': int(np.quantile(latencies, 0.90)),
+            '95th': int(np.quantile(latencies, 0.95)),
+            '99th': int(np.quantile(latencies, 0.99)),
+            'max': int(latencies.max()),
+            'min': int(latencies.min()),
+        },
+        'bytes_in': {'total': bytes_in, 'mean': bytes_in / n_requests},
+        'bytes_out': {'total': bytes_out, 'mean': bytes_out / n_requests},
+        'earliest': _format_timestamp_ns(earliest),
+        'latest': _format_timestamp_ns(latest),
+        'end': _format_timestamp_ns(end),
+        'duration': duration,
+        'wait': wait,
+        'requests': n_requests,
+        'rate': rate,
+        'throughput': throughput,
+        'success': n_success / n_requests,
+        'status_codes': status_codes,
+        'errors': errors,
+    }  # type: ignore
@@ -0,0 +1,204 @@
+"""scrape prometheus metrics of nodes during attacks
+
+only series whose names match a category are kept, so that client-side
+latency can be compared against node-internal behavior such as cpu usage,
+memory, database reads, or rpc queue depth
+
+metric names differ between clients, so each category lists name fragments
+used by reth, geth, and erigon
+"""
+from __future__ import annotations
+
+import typing
+
+from ... import spec
+
+
+default_scrape_interval = 1.0
+default_scrape_timeout = 2.0
+
+# maximum number of series stored per category, e.g. for many label values
+max_series_per_category = 20
+
+default_node_metric_categories: typing.Mapping[str, typing.Sequence[str]] = {
+    'cpu': [
+        'process_cpu_seconds_total',
+        'system_cpu_procload',
+    ],
+    'memory': [
+        'process_resident_memory_bytes',
+        'system_memory_used',
+        'jemalloc_resident',
+    ],
+    'db_reads': [
+        'db_read',
+        'database_read',
+        'db_get',
+        'chaindata_disk_read',
+    ],
+    'rpc_queue': [
+        'rpc_queue',
+        'rpc_requests_in_flight',
+        'rpc_pending',
+        'rpc_active',
+    ],
+}
+
+
+class PrometheusScraper:
+    """scrape prometheus metrics endpoint in a background thread"""
+
+    def __init__(
+        self,
+        url: str,
+        interval: float | None = None,
+        categories: typing.Mapping[str, typing.Sequence[str]] | None = None,
+    ) -> None:
+        import threading
+
+        if interval is None:
+            interval = default_scrape_interval
+        if categories is None:
+            categories = default_node_metric_categories
+        self.url = url
+        self.interval = interval
+        self.categories = categories
+        self._times: list[float] = []
+        self._scrapes: list[typing.Mapping[str, float] | None] = []
+        self._stop = threading.Event()
+        self._thread = threading.Thread(target=self._run, daemon=True)
+
+    def start(self) -> None:
+        import time
+
+        self._t_start = time.time()
+        self._thread.start()
+
+    def stop(self) -> spec.NodeMetricsTimeSeries:
+        self._stop.set()
+        self._thread.join()
+        self._scrape()
+        return _create_node_metrics_time_series(
+            interval=self.interval,
+            times=self._times,
+            scrapes=self._scrapes,
+            categories=self.categories,
+        )
+
+    def _run(self) -> None:
+        self._scrape()
+        while not self._stop.wait(self.interval):
+            self._scrape()
+
+    def _scrape(self) -> None:
+        import time
+
+        t_scrape = time.time() - self._t_start
+        try:
+            metrics: typing.Mapping[str, float] | None = scrape_metrics(
+                self.url
+            )
+        except Exception:
+            metrics = None
+        self._times.append(t_scrape)
+        self._scrapes.append(metrics)
+
+
+def scrape_metrics(
+    url: str, timeout: float | None = None
+) -> typing.Mapping[str, float]:
+    """fetch and parse prometheus metrics endpoint"""
+    import urllib.request
+
+    if timeout is None:
+        timeout = default_scrape_timeout
+    with urllib.request.urlopen(url, timeout=timeout) as response:
+        text = response.read().decode()
+    return parse_prometheus_metrics(text)
+
+
+def parse_prometheus_metrics(text: str) -> typing.Mapping[str, float]:
+    """parse prometheus text format into {series: value}
+
+    series keys include labels, e.g. 'rpc_requests{method="eth_call"}'
+    """
+    metrics = {}
+    for line in text.splitlines():
+        line = line.strip()
+        if line == '' or line.startswith('#'):
+            continue
+        if '}' in line:
+            series, _, rest = line.rpartition('}')
+            series += '}'
+        else:
+            series, _, rest = line.partition(' ')
+        fields = rest.split()
+        if len(fields) == 0:
+            continue
+        try:
+            metrics[series.strip()] = float(fields[0])
+        except ValueError:
+            continue
+    return metrics
+
+
+def get_series_category(
+    series: str,
+    categories: typing.Mapping[str, typing.Sequence[str]],
+) -> str | None:
+    """get category of series, or None if it does not match any category"""
+    name = series.split('{')[0]
+    if name.endswith('_bucket'):
+        return None
+    for category, fragments in categories.items():
+        if any(fragment in name for fragment in fragments):
+            return category
+    return None
+
+
+def is_counter_series(series: str) -> bool:
+    """whether series is a cumulative counter instead of a gauge"""
+    name = series.split('{')[0]
+    return name.endswith(('_total', '_count', '_sum'))
+
+
+def _create_node_metrics_time_series(
+    *,
+    interval: float,
+    times: typing.Sequence[float],
+    scrapes: typing.Sequence[typing.Mapping[str, float] | None],
+    categories: typing.Mapping[str, typing.Sequence[str]],
+) -> spec.NodeMetricsTimeSeries:
+    # select series of each category
+    selected: dict[str, list[str]] = {}
+    for scrape in scrapes:
+        if scrape is None:
+            continue
+        for series in scrape.keys():
+            category = get_series_category(series, categories)
+            if category is None:
+                continue
+            category_series = selected.setdefault(category, [])
+            if (
+                series not in category_series
+                and len(category_series) < max_series_per_category
+            ):
+                category_series.append(series)
+
+    all_series = [
+        series
+        for category_series in selected.values()
+        for series in category_series
+    ]
+    return {
+        'interval': interval,
+        'time': list(times),
+        'series': {
+            series: [
+                None if scrape is None else scrape.get(series)
+                for scrape in scrapes
+            ]
+            for series in all_series
+        },
+        'categories': selected,
+    }
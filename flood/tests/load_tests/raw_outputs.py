@@ -0,0 +1,193 @@
+"""store raw outputs of attacks as parquet files
+
+each node's raw outputs are saved as a single parquet file next to
+results.json, with 1 row per response and columns:
+- attack_index: index of attack in test
+- timestamp: send time of request, in unix nanoseconds
+- latency: latency of response, in nanoseconds
+- status_code: http status code, 0 if request failed without a response
+- bytes_out: bytes sent in request
+- bytes_in: bytes received in response
+- error: error of request, or null
+- seq: sequence number of request in attack
+- method: RPC method of the request's call, 'batch' for batches of mixed
+  methods, null if the call is not known
+- transport: http method of request, or WS for websocket requests
+- url: url of request
+- response: response body, as raw bytes
+"""
+from __future__ import annotations
+
+import typing
+
+from . import deep_utils
+
+if typing.TYPE_CHECKING:
+    import polars as pl
+
+
+raw_output_columns = [
+    'attack_index',
+    'timestamp',
+    'latency',
+    'status_code',
+    'bytes_out',
+    'bytes_in',
+    'error',
+    'seq',
+    'method',
+    'transport',
+    'url',
+    'response',
+]
+
+
+def annotate_raw_output(
+    raw_output: bytes,
+    calls: typing.Sequence[typing.Any],
+) -> bytes:
+    """convert raw output to json lines with the RPC method of each response
+
+    responses are paired with their calls as in deep metrics
+    """
+    import base64
+
+    from . import native
+
+    df = deep_utils._convert_raw_vegeta_output_to_dataframe(raw_output)
+    rpc_methods = deep_utils.get_response_rpc_methods(df, calls)
+    results = [
+        {
+            'seq': row['index'],
+            'code': row['status_code'],
+            'timestamp': row['timestamp'],
+            'latency': row['latency'],
+            'bytes_out': row['bytes_out'],
+            'bytes_in': row['bytes_in'],
+            'error': row['error'] or '',
+            'body': base64.b64decode(row['response'] or ''),
+            'method': row['method'],
+            'url': row['url'],
+            'rpc_method': rpc_method,
+        }
+        for row, rpc_method in zip(df.to_dicts(), rpc_methods)
+    ]
+    return native._encode_native_results(results)
+
+
+def convert_raw_outputs_to_dataframe(
+    raw_outputs: typing.Sequence[str | None],
+) -> pl.DataFrame:
+    """convert encoded raw outputs of each attack into a single dataframe"""
+    import base64
+    import polars as pl
+
+    dfs = []
+    for attack_index, encoded in enumerate(raw_outputs):
+        if encoded is None:
+            continue
+        raw_output = deep_utils.decode_raw_vegeta_output(encoded)
+        df = deep_utils._convert_raw_vegeta_output_to_dataframe(raw_output)
+        responses = [
+            None if response is None else base64.b64decode(response)
+            for response in df['response']
+        ]
+        df = df.with_columns(
+            pl.lit(attack_index, dtype=pl.Int64).alias('attack_index'),
+            pl.col('index').alias('seq'),
+            pl.col('method').alias('transport'),
+            pl.col('rpc_method').alias('method'),
+            pl.Series('response', responses, dtype=pl.Binary),
+        )
+        dfs.append(df.select(raw_output_columns))
+
+    if len(dfs) == 0:
+        return pl.DataFrame(
+            {column: [] for column in raw_output_columns},
+            schema=_get_raw_output_schema(),
+        )
+    return pl.concat(dfs)
+
+
+def convert_dataframe_to_raw_outputs(
+    df: pl.DataFrame,
+    n_attacks: int,
+) -> typing.Sequence[str | None]:
+    """convert raw output dataframe back into encoded raw output per attack
+
+    raw outputs are encoded in the json lines format of the native engine
+    """
+    import polars as pl
+    from . import native
+
+    raw_outputs: list[str | None] = []
+    for attack_index in range(n_attacks):
+        attack_df = df.filter(pl.col('attack_index') == attack_index)
+        if len(attack_df) == 0:
+            raw_outputs.append(None)
+            continue
+        results = [
+            {
+                'seq': row['seq'],
+                'code': row['status_code'],
+                'timestamp': row['timestamp'],
+                'latency': row['latency'],
+                'bytes_out': row['bytes_out'],
+                'bytes_in': row['bytes_in'],
+                'error': row['error'] or '',
+                'body': row['response'] or b'',
+                'method': row['transport'],
+                'url': row['url'],
+                'rpc_method': row['method'],
+            }
+            for row in attack_df.to_dicts()
+        ]
+        encoded = native._encode_native_results(results)
+        raw_outputs.append(deep_utils.encode_raw_vegeta_output(encoded))
+    return raw_outputs
+
+
+def save_raw_outputs(
+    raw_outputs: typing.Sequence[str | None],
+    path: str,
+) -> None:
+    """save encoded raw outputs of each attack as a parquet file"""
+    import os
+
+    dirname = os.path.dirname(path)
+    if dirname != '':
+        os.makedirs(dirname, exist_ok=True)
+    df = convert_raw_outputs_to_dataframe(raw_outputs)
+    df.write_parquet(path)
+
+
+def load_raw_outputs(
+    path: str,
+    attack_index: int | None = None,
+) -> pl.DataFrame:
+    """load raw outputs from parquet file, optionally of a single attack"""
+    import polars as pl
+
+    df = pl.read_parquet(path)
+    if attack_index is not None:
+        df = df.filter(pl.col('attack_index') == attack_index)
+    return df
+
+
+def _get_raw_output_schema() -> typing.Mapping[str, typing.Any]:
+    import polars as pl
+
+    return {
+        'attack_index': pl.Int64,
+        'timestamp': pl.Int64,
+        'latency': pl.Int64,
+        'status_code': pl.Int64,
+        'bytes_out': pl.Int64,
+        'bytes_in': pl.Int64,
+        'error': pl.Utf8,
+        'seq': pl.Int64,
+        'method': pl.Utf8,
+        'transport': pl.Utf8,
+        'url': pl.Utf8,
+        'response': pl.Binary,
+    }
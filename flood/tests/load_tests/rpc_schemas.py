@@ -0,0 +1,262 @@
+"""validate rpc results against per-method schemas
+
+schemas follow the ethereum execution-apis spec, written in the subset of
+json schema used by that spec: type, pattern, properties, required, items,
+and oneOf
+
+methods without a schema, e.g. trace methods, are not validated
+"""
+from __future__ import annotations
+
+import typing
+
+
+Schema = typing.Mapping[str, typing.Any]
+
+uint_pattern = '^0x(0|[1-9a-f][0-9a-f]*)$'
+bytes_pattern = '^0x[0-9a-f]*$'
+bytes8_pattern = '^0x[0-9a-f]{16}$'
+bytes32_pattern = '^0x[0-9a-f]{64}$'
+bytes256_pattern = '^0x[0-9a-f]{512}$'
+address_pattern = '^0x[0-9a-f]{40}$'
+
+uint_schema: Schema = {'type': 'string', 'pattern': uint_pattern}
+data_schema: Schema = {'type': 'string', 'pattern': bytes_pattern}
+bytes8_schema: Schema = {'type': 'string', 'pattern': bytes8_pattern}
+hash32_schema: Schema = {'type': 'string', 'pattern': bytes32_pattern}
+bytes256_schema: Schema = {'type': 'string', 'pattern': bytes256_pattern}
+address_schema: Schema = {'type': 'string', 'pattern': address_pattern}
+null_schema: Schema = {'type': 'null'}
+
+
+def _nullable(schema: Schema) -> Schema:
+    return {'oneOf': [schema, null_schema]}
+
+
+def _array(items: Schema) -> Schema:
+    return {'type': 'array', 'items': items}
+
+
+log_schema: Schema = {
+    'type': 'object',
+    'required': [
+        'address',
+        'topics',
+        'data',
+        'blockNumber',
+        'transactionHash',
+        'transactionIndex',
+        'blockHash',
+        'logIndex',
+    ],
+    'properties': {
+        'removed': {'type': 'boolean'},
+        'logIndex': uint_schema,
+        'transactionIndex': uint_schema,
+        'transactionHash': hash32_schema,
+        'blockHash': hash32_schema,
+        'blockNumber': uint_schema,
+        'address': address_schema,
+        'data': data_schema,
+        'topics': _array(hash32_schema),
+    },
+}
+
+transaction_schema: Schema = {
+    'type': 'object',
+    'required': [
+        'hash',
+        'from',
+        'to',
+        'nonce',
+        'gas',
+        'value',
+        'input',
+        'blockHash',
+        'blockNumber',
+        'transactionIndex',
+    ],
+    'properties': {
+        'type': uint_schema,
+        'hash': hash32_schema,
+        'from': address_schema,
+        'to': _nullable(address_schema),
+        'nonce': uint_schema,
+        'gas': uint_schema,
+        'value': uint_schema,
+        'input': data_schema,
+        'gasPrice': uint_schema,
+        'maxFeePerGas': uint_schema,
+        'maxPriorityFeePerGas': uint_schema,
+        'chainId': uint_schema,
+        'v': uint_schema,
+        'r': uint_schema,
+        's': uint_schema,
+        'blockHash': _nullable(hash32_schema),
+        'blockNumber': _nullable(uint_schema),
+        'transactionIndex': _nullable(uint_schema),
+    },
+}
+
+receipt_schema: Schema = {
+    'type': 'object',
+    'required': [
+        'transactionHash',
+        'transactionIndex',
+        'blockHash',
+        'blockNumber',
+        'from',
+        'to',
+        'cumulativeGasUsed',
+        'gasUsed',
+        'contractAddress',
+        'logs',
+        'logsBloom',
+    ],
+    'properties': {
+        'type': uint_schema,
+        'transactionHash': hash32_schema,
+        'transactionIndex': uint_schema,
+        'blockHash': hash32_schema,
+        'blockNumber': uint_schema,
+        'from': address_schema,
+        'to': _nullable(address_schema),
+        'cumulativeGasUsed': uint_schema,
+        'gasUsed': uint_schema,
+        'contractAddress': _nullable(address_schema),
+        'logs': _array(log_schema),
+        'logsBloom': bytes256_schema,
+        'root': hash32_schema,
+        'status': uint_schema,
+        'effectiveGasPrice': uint_schema,
+    },
+}
+
+block_schema: Schema = {
+    'type': 'object',
+    'required': [
+        'hash',
+        'parentHash',
+        'sha3Uncles',
+        'miner',
+        'stateRoot',
+        'transactionsRoot',
+        'receiptsRoot',
+        'logsBloom',
+        'number',
+        'gasLimit',
+        'gasUsed',
+        'timestamp',
+        'extraData',
+        'transactions',
+        'uncles',
+    ],
+    'properties': {
+        'hash': hash32_schema,
+        'parentHash': hash32_schema,
+        'sha3Uncles': hash32_schema,
+        'miner': address_schema,
+        'stateRoot': hash32_schema,
+        'transactionsRoot': hash32_schema,
+        'receiptsRoot': hash32_schema,
+        'logsBloom': bytes256_schema,
+        'difficulty': uint_schema,
+        'number': uint_schema,
+        'gasLimit': uint_schema,
+        'gasUsed': uint_schema,
+        'timestamp': uint_schema,
+        'extraData': data_schema,
+        'mixHash': hash32_schema,
+        'nonce': bytes8_schema,
+        'size': uint_schema,
+        'baseFeePerGas': uint_schema,
+        'withdrawalsRoot': hash32_schema,
+        'transactions': {
+            'oneOf': [_array(hash32_schema), _array(transaction_schema)],
+        },
+        'uncles': _array(hash32_schema),
+    },
+}
+
+fee_history_schema: Schema = {
+    'type': 'object',
+    'required': ['oldestBlock', 'baseFeePerGas', 'gasUsedRatio'],
+    'properties': {
+        'oldestBlock': uint_schema,
+        'baseFeePerGas': _array(uint_schema),
+        'gasUsedRatio': _array({'type': 'number'}),
+        'reward': _array(_array(uint_schema)),
+    },
+}
+
+# results that are null for missing or pending objects are nullable
+rpc_result_schemas: typing.Mapping[str, Schema] = {
+    'eth_blockNumber': uint_schema,
+    'eth_chainId': uint_schema,
+    'eth_gasPrice': uint_schema,
+    'eth_maxPriorityFeePerGas': uint_schema,
+    'eth_getBalance': uint_schema,
+    'eth_getTransactionCount': uint_schema,
+    'eth_getCode': data_schema,
+    'eth_getStorageAt': hash32_schema,
+    'eth_call': data_schema,
+    'eth_estimateGas': uint_schema,
+    'eth_getLogs': _array(log_schema),
+    'eth_feeHistory': fee_history_schema,
+    'eth_getBlockByNumber': _nullable(block_schema),
+    'eth_getBlockByHash': _nullable(block_schema),
+    'eth_getTransactionByHash': _nullable(transaction_schema),
+    'eth_getTransactionReceipt': _nullable(receipt_schema),
+}
+
+
+def get_rpc_result_schema(method: str | None) -> Schema | None:
+    """get schema of method's result, or None if method has no schema"""
+    if method is None:
+        return None
+    return rpc_result_schemas.get(method)
+
+
+def is_valid_rpc_result(result: typing.Any, schema: Schema) -> bool:
+    """whether result matches schema"""
+    import re
+
+    if 'oneOf' in schema:
+        return any(
+            is_valid_rpc_result(result, option) for option in schema['oneOf']
+        )
+
+    schema_type = schema.get('type')
+    if schema_type == 'null':
+        return result is None
+    elif schema_type == 'boolean':
+        return isinstance(result, bool)
+    elif schema_type == 'number':
+        return isinstance(result, (int, float)) and not isinstance(
+            result, bool
+        )
+    elif schema_type == 'string':
+        if not isinstance(result, str):
+            return False
+        pattern = schema.get('pattern')
+        return pattern is None or re.match(pattern, result) is not None
+    elif schema_type == 'array':
+        if not isinstance(result, list):
+            return False
+        items = schema.get('items')
+        return items is None or all(
+            is_valid_rpc_result(item, items) for item in result
+        )
+    elif schema_type == 'object':
+        if not isinstance(result, dict):
+            return False
+        if any(key not in result for key in schema.get('required', [])):
+            return False
+        for key, property_schema in schema.get('properties', {}).items():
+            if key in result and not is_valid_rpc_result(
+                result[key], property_schema
+            ):
+                return False
+        return True
+    else:
+        raise Exception('unknown schema type: ' + str(schema_type))
@@ -0,0 +1,201 @@
+"""adaptive search for the highest rate that a node sustains within an SLO
+
+rates grow exponentially until the SLO is violated, then the interval between
+the highest passing rate and the lowest failing rate is binary searched
+"""
+from __future__ import annotations
+
+import typing
+
+import flood
+from flood import spec
+from flood import user_io
+from . import load_test_runs
+from . import vegeta
+
+
+default_slo: spec.ThroughputSLO = {'max_p99': 0.5, 'min_success': 0.999}
+default_search_start_rate = 16
+default_search_max_rate = 65536
+default_search_duration = 10
+default_search_growth = 2
+default_search_precision = 0.05
+
+# samples whose actual rate falls this far below target rate are not sustained
+min_actual_rate_ratio = 0.95
+
+
+def run_throughput_search(
+    *,
+    node: spec.NodeShorthand,
+    test_name: str,
+    slo: spec.ThroughputSLO | None = None,
+    start_rate: int | None = None,
+    max_rate: int | None = None,
+    duration: int | None = None,
+    precision: float | None = None,
+    network: str | None = None,
+    random_seed: spec.RandomSeed | None = None,
+    vegeta_args: spec.VegetaArgsShorthand | None = None,
+    batch_size: int | None = None,
+    weights: typing.Mapping[str, float] | None = None,
+    blocks: spec.BlockSelection | None = None,
+    include_deep_output: typing.Sequence[spec.DeepOutput] | None = None,
+    engine: spec.LoadEngine | None = None,
+    verbose: bool | int = False,
+) -> spec.ThroughputSearchOutput:
+    """find highest rate that meets slo, each sample is a separate attack
+
+    search stops once the interval between the highest passing rate and the
+    lowest failing rate is within precision (relative to the passing rate)
+    """
+
+    # parse inputs
+    node = user_io.parse_node(node)
+    if node['remote'] is not None:
+        raise Exception('throughput search not supported for remote nodes')
+    if slo is None:
+        slo = default_slo
+    if start_rate is None:
+        start_rate = default_search_start_rate
+    if max_rate is None:
+        max_rate = default_search_max_rate
+    if duration is None:
+        duration = default_search_duration
+    if precision is None:
+        precision = default_search_precision
+    if network is None:
+        if isinstance(node['network'], int):
+            raise Exception(
+                flood.generators.get_unsupported_chain_id_message(
+                    node['network']
+                )
+            )
+        elif node['network'] is None:
+            raise Exception('network could not be determined')
+        network = node['network']
+    if start_rate < 1:
+        raise Exception('start_rate must be at least 1')
+    if max_rate < start_rate:
+        raise Exception('max_rate must be at least start_rate')
+
+    results: dict[int, spec.LoadTestOutputDatum] = {}
+    tested_rates = []
+    passed = []
+    highest_passing: int | None = None
+    lowest_failing: int | None = None
+    rate: int | None = start_rate
+    while rate is not None:
+        # run sample
+        if verbose:
+            flood.user_io.print_timestamped(
+                'Running attack at rate = ' + str(rate) + ' rps'
+            )
+        test = flood.generate_test(
+            test_name=test_name,
+            rates=[rate],
+            durations=[duration],
+            vegeta_args=vegeta_args,
+            network=network,
+            random_seed=random_seed,
+            flood_version=flood.get_flood_version(),
+            batch_size=batch_size,
+            weights=weights,
+            blocks=blocks,
+            rpc_url=node['url'],
+        )
+        attack = test['attacks'][0]
+        result = vegeta.run_vegeta_attack(
+            url=node['url'],
+            calls=attack['calls'],
+            duration=attack['duration'],
+            rate=attack['rate'],
+            vegeta_args=attack['vegeta_args'],
+            verbose=verbose >= 2,
+            include_deep_output=include_deep_output,
+            engine=engine,
+        )
+
+        # evaluate sample
+        sample_passed = meets_throughput_slo(result, slo)
+        results[rate] = result
+        tested_rates.append(rate)
+        passed.append(sample_passed)
+        if sample_passed:
+            highest_passing = rate
+        else:
+            lowest_failing = rate
+        if verbose:
+            flood.user_io.print_timestamped(
+                'Rate = '
+                + str(rate)
+                + ' rps '
+                + ('met' if sample_passed else 'violated')
+                + ' SLO'
+            )
+
+        rate = _get_next_search_rate(
+            rate=rate,
+            highest_passing=highest_passing,
+            lowest_failing=lowest_failing,
+            max_rate=max_rate,
+            precision=precision,
+        )
+
+    summary: spec.ThroughputSearchSummary = {
+        'slo': slo,
+        'max_sustainable_rate': highest_passing,
+        'tested_rates': tested_rates,
+        'passed': passed,
+    }
+    output = load_test_runs._format_load_test_output(
+        results=[results[rate] for rate in sorted(results.keys())],
+        include_deep_output=include_deep_output,
+    )
+    return {'summary': summary, 'results': output}
+
+
+def meets_throughput_slo(
+    result: spec.LoadTestOutputDatum, slo: spec.ThroughputSLO
+) -> bool:
+    """whether attack met slo and was able to send at its target rate"""
+    success = result['success']
+    p99 = result['p99']
+    actual_rate = result['actual_rate']
+    target_rate = result['target_rate']
+    if success is None or success < slo['min_success']:
+        return False
+    if p99 is None or p99 > slo['max_p99']:
+        return False
+    if target_rate is not None and (
+        actual_rate is None or actual_rate < target_rate * min_actual_rate_ratio
+    ):
+        return False
+    return True
+
+
+def _get_next_search_rate(
+    *,
+    rate: int,
+    highest_passing: int | None,
+    lowest_failing: int | None,
+    max_rate: int,
+    precision: float,
+    growth: int = default_search_growth,
+) -> int | None:
+    """return None when search is complete"""
+
+    # exponential phase
+    if lowest_failing is None:
+        if rate >= max_rate:
+            return None
+        return min(rate * growth, max_rate)
+
+    # binary search phase
+    if highest_passing is None:
+        floor = 0
+    else:
+        floor = highest_passing
+    if lowest_failing - floor <= max(1, floor * precision):
+        return None
+    return (floor + lowest_failing) // 2
@@ -3,8 +3,14 @@ from __future__ import annotations
 
 import typing
 
+from ... import generators
 from ... import spec
 from . import deep_utils
+from . import latency_histograms
+from . import loader_monitoring
+from . import native
+from . import prometheus
+from . import raw_outputs
 
 
 def run_vegeta_attack(
@@ -13,115 +19,223 @@ def run_vegeta_attack(
     rate: int,
     calls: typing.Sequence[typing.Any],
     duration: int,
+    concurrency: int | None = None,
+    think_time: float | None = None,
+    offsets: typing.Sequence[float] | None = None,
     vegeta_args: str | None = None,
     verbose: bool = False,
     include_deep_output: typing.Sequence[spec.DeepOutput] | None = None,
+    engine: spec.LoadEngine | None = None,
+    metrics_url: str | None = None,
+    count_timeouts: bool = False,
 ) -> spec.LoadTestOutputDatum:
-    attack = _construct_vegeta_attack(
-        calls=calls,
-        url=url,
-        verbose=verbose,
-    )
-    attack_output = _vegeta_attack(
-        schedule_dir=attack['schedule_dir'],
-        duration=duration,
-        rate=rate,
-        vegeta_args=vegeta_args,
-        verbose=verbose,
-    )
+    """run attack using the specified load engine
+
+    default engine is vegeta, or native for websocket nodes and replays
+
+    if concurrency is given, run a closed-loop attack where each of the
+    concurrency workers sends its next request once the previous completes
+
+    if offsets are given, send each call at its offset in seconds from the
+    start of the attack, as when replaying a traffic log
+
+    if metrics_url is given, the node's prometheus endpoint is scraped
+    throughout the attack
+
+    if count_timeouts, report the longest run of timed out requests
+    """
+    if engine is None:
+        if native.is_websocket_url(url) or offsets is not None:
+            engine = 'native'
+        else:
+            engine = 'vegeta'
+    if engine == 'vegeta' and native.is_websocket_url(url):
+        raise Exception('vegeta engine does not support websocket nodes')
+    if think_time is not None and concurrency is None:
+        raise Exception('think_time requires concurrency')
+    if offsets is not None and concurrency is not None:
+        raise Exception('cannot use both offsets and concurrency')
+    if engine not in ['vegeta', 'native']:
+        raise Exception('unknown engine: ' + str(engine))
+
+    if engine == 'vegeta':
+        if think_time is not None:
+            raise Exception('think_time not supported by vegeta engine')
+        if offsets is not None:
+            raise Exception('offsets not supported by vegeta engine')
+    elif vegeta_args is not None:
+        raise Exception('vegeta_args not supported by native engine')
+
+    # chain head is needed to break down latency by block age
+    head_block = None
+    if include_deep_output is not None and 'metrics' in include_deep_output:
+        head_block = _get_head_block(url)
+
+    # sample loader and node metrics while attack runs
+    monitor = loader_monitoring.LoaderMonitor()
+    scraper = None
+    if metrics_url is not None:
+        scraper = prometheus.PrometheusScraper(metrics_url)
+    monitor.start()
+    if scraper is not None:
+        scraper.start()
+
+    try:
+        if engine == 'vegeta':
+            if concurrency is not None:
+                # vegeta uses rate=0 for attacks with a fixed number of workers
+                rate = 0
+            attack_output = _vegeta_attack(
+                calls=calls,
+                url=url,
+                duration=duration,
+                rate=rate,
+                n_workers=concurrency,
+                max_workers=concurrency,
+                vegeta_args=vegeta_args,
+                verbose=verbose,
+            )
+        else:
+            attack_output = native.run_native_attack(
+                url=url,
+                rate=rate,
+                duration=duration,
+                calls=calls,
+                concurrency=concurrency,
+                think_time=think_time,
+                offsets=offsets,
+                verbose=verbose,
+            )
+    finally:
+        # stop sampling threads even if the attack fails
+        loader_metrics = monitor.stop()
+        node_metrics = None
+        if scraper is not None:
+            node_metrics = scraper.stop()
+
     report = _create_vegeta_report(
         attack_output=attack_output,
         target_rate=rate,
+        target_concurrency=concurrency,
         target_duration=duration,
         include_deep_output=include_deep_output,
         calls=calls,
+        engine=engine,
+        offsets=offsets,
+        loader_metrics=loader_metrics,
+        node_metrics=node_metrics,
+        count_timeouts=count_timeouts,
+        head_block=head_block,
     )
     return report
 
 
-def _construct_vegeta_attack(
-    calls: typing.Sequence[typing.Any],
-    url: str,
-    schedule_dir: str | None = None,
-    verbose: bool = False,
-) -> typing.Mapping[str, str]:
-    import json
-    import os
-    import tempfile
-
-    headers = {'Content-Type': 'application/json'}
-
-    # determine output directory
-    if schedule_dir is None:
-        schedule_dir = tempfile.mkdtemp()
-
-    # create calls file
-    call_paths = []
-    for c, call in enumerate(calls):
-        vegeta_calls_path = os.path.join(
-            schedule_dir, 'vegeta_calls_' + str(c) + '.json'
-        )
-        with open(vegeta_calls_path, 'w') as f:
-            f.write(json.dumps(call))
-        call_paths.append(vegeta_calls_path)
-
-    # create targets specification
-    vegeta_targets_path = os.path.join(schedule_dir, 'vegeta_targets')
-    with open(vegeta_targets_path, 'w') as f:
-        for c, call in enumerate(calls):
-            f.write('POST ' + url + '\n')
-            for key, value in headers.items():
-                f.write(key + ': ' + value + '\n')
-            f.write('@' + call_paths[c] + '\n')
-            f.write('\n')
-
-    # output summary
-    if verbose:
-        print('running vegeta attack...')
-        print('- targets:', vegeta_targets_path)
-        print('- calls:', vegeta_calls_path)
-
-    return {
-        'schedule_dir': schedule_dir,
-        'vegeta_calls_path': vegeta_calls_path,
-        'vegeta_targets_path': vegeta_targets_path,
-    }
+def _get_head_block(url: str) -> int | None:
+    """get block number of node's chain head, or None if not available"""
+    try:
+        return generators.get_chain_head(url).get_block_number()
+    except Exception:
+        return None
 
 
 def _vegeta_attack(
-    schedule_dir: str,
+    calls: typing.Sequence[typing.Any],
+    url: str,
     *,
     duration: int | None = None,
     rate: int | None = None,
     max_connections: int | None = None,
+    n_workers: int | None = None,
     max_workers: int | None = None,
     n_cpus: int | None = None,
     report_path: str | None = None,
     vegeta_args: str | None = None,
     verbose: bool = False,
 ) -> bytes:
-    import os
+    """run vegeta attack, streaming targets to vegeta's stdin as needed
+
+    targets use vegeta's JSON format and are read lazily, so calls are only
+    constructed and encoded shortly before they are sent
+    """
     import subprocess
+    import threading
+
+    if len(calls) == 0:
+        raise Exception('must specify at least one call')
 
     # construct command
-    cmd = 'vegeta attack'
-    cmd += ' -targets=' + os.path.join(schedule_dir, 'vegeta_targets')
+    cmd = 'vegeta attack -format=json -lazy'
     if rate is not None:
         cmd += ' -rate=' + str(rate)
     if duration is not None:
         cmd += ' -duration=' + str(duration) + 's'
     if max_connections is not None:
         cmd += ' -max-connections=' + str(max_connections)
+    if n_workers is not None:
+        cmd += ' -workers=' + str(n_workers)
     if max_workers is not None:
         cmd += ' -max-workers=' + str(max_workers)
     if vegeta_args is not None:
         cmd += ' ' + vegeta_args
 
     if verbose:
+        print('running vegeta attack...')
+        print('- targets: streamed to stdin,', len(calls), 'calls')
         print('- command:', cmd)
 
-    # run command
-    return subprocess.check_output(cmd.split(' '))
+    # run command, writing targets from a separate thread
+    process = subprocess.Popen(
+        cmd.split(' '),
+        stdin=subprocess.PIPE,
+        stdout=subprocess.PIPE,
+    )
+    writer = threading.Thread(
+        target=_write_vegeta_targets,
+        kwargs={'stream': process.stdin, 'calls': calls, 'url': url},
+        daemon=True,
+    )
+    writer.start()
+    assert process.stdout is not None
+    output = process.stdout.read()
+    returncode = process.wait()
+    writer.join()
+    if returncode != 0:
+        raise subprocess.CalledProcessError(returncode, cmd)
+    return output
+
+
+def _write_vegeta_targets(
+    stream: typing.IO[bytes],
+    calls: typing.Sequence[typing.Any],
+    url: str,
+) -> None:
+    """write targets until vegeta stops reading
+
+    targets are cycled like vegeta does for targets that are read eagerly
+    """
+    import base64
+    import json
+
+    header = {'Content-Type': ['application/json']}
+    try:
+        while True:
+            for call in calls:
+                body = base64.b64encode(json.dumps(call).encode()).decode()
+                target = {
+                    'method': 'POST',
+                    'url': url,
+                    'header': header,
+                    'body': body,
+                }
+                stream.write(json.dumps(target).encode() + b'\n')
+    except (BrokenPipeError, ValueError, OSError):
+        # vegeta exits and closes its stdin once the attack is over
+        pass
+    finally:
+        try:
+            stream.close()
+        except OSError:
+            pass
 
 
 def _create_vegeta_report(
@@ -130,45 +244,100 @@ def _create_vegeta_report(
     target_duration: int,
     include_deep_output: typing.Sequence[spec.DeepOutput] | None,
     calls: typing.Sequence[typing.Any],
+    engine: spec.LoadEngine = 'vegeta',
+    target_concurrency: int | None = None,
+    offsets: typing.Sequence[float] | None = None,
+    loader_metrics: spec.LoaderMetrics | None = None,
+    node_metrics: spec.NodeMetricsTimeSeries | None = None,
+    count_timeouts: bool = False,
+    head_block: int | None = None,
 ) -> spec.LoadTestOutputDatum:
     import json
     import subprocess
 
-    cmd = 'vegeta report -type json'
-    report_output = (
-        subprocess.check_output(cmd.split(' '), input=attack_output)
-        .decode()
-        .strip()
-    )
-    report: spec.RawLoadTestOutputDatum = json.loads(report_output)
+    report: spec.RawLoadTestOutputDatum
+    if engine == 'native':
+        report = native.create_native_report(attack_output)
+    else:
+        cmd = 'vegeta report -type json'
+        report_output = (
+            subprocess.check_output(cmd.split(' '), input=attack_output)
+            .decode()
+            .strip()
+        )
+        report = json.loads(report_output)
 
     if 'min' in report['latencies']:
         latency_min = report['latencies']['min'] / 1e9
     else:
         latency_min = None
 
+    # compute latency histogram
+    if engine == 'native':
+        latency_histogram = latency_histograms.create_latency_histogram(
+            [
+                result['latency'] / 1e9
+                for result in native.decode_native_results(attack_output)
+            ]
+        )
+    else:
+        latency_histogram = _create_vegeta_histogram(
+            attack_output=attack_output,
+            min_latency=latency_min,
+            max_latency=report['latencies']['max'] / 1e9,
+        )
+
+    if count_timeouts:
+        max_consecutive_timeouts: int | None = (
+            _count_max_consecutive_timeouts(
+                attack_output=attack_output, engine=engine
+            )
+        )
+    else:
+        max_consecutive_timeouts = None
+
     # compute deep data
     deep_raw_output = None
     deep_metrics = None
     deep_rpc_error_pairs = None
+    deep_method_metrics = None
+    deep_time_series = None
+    deep_slowest_calls = None
+    deep_latency_breakdowns = None
     if include_deep_output is None:
         include_deep_output = []
     if 'raw' in include_deep_output:
-        deep_raw_output = deep_utils.encode_raw_vegeta_output(attack_output)
+        # raw output records the RPC method of each response's call
+        deep_raw_output = deep_utils.encode_raw_vegeta_output(
+            raw_outputs.annotate_raw_output(attack_output, calls)
+        )
     if 'metrics' in include_deep_output:
         if 'metrics' in include_deep_output:
             (
                 deep_metrics,
                 deep_rpc_error_pairs,
+                deep_method_metrics,
+                deep_time_series,
+                deep_slowest_calls,
+                deep_latency_breakdowns,
             ) = deep_utils.compute_deep_datum(
                 raw_output=attack_output,
                 target_rate=target_rate,
+                target_concurrency=target_concurrency,
                 target_duration=target_duration,
                 calls=calls,
+                offsets=offsets,
+                head_block=head_block,
             )
 
+    if target_concurrency is not None:
+        use_target_rate = None
+    else:
+        use_target_rate = target_rate
+
     return {
-        'target_rate': target_rate,
+        'target_rate': use_target_rate,
+        'target_concurrency': target_concurrency,
         'actual_rate': report['rate'],
         'target_duration': target_duration,
         'actual_duration': report['duration'] / 1e9,
@@ -189,8 +358,111 @@ def _create_vegeta_report(
         'last_request_timestamp': report['latest'],
         'last_response_timestamp': report['end'],
         'final_wait_time': report['wait'] / 1e9,
+        'latency_histogram': latency_histogram,
+        'max_consecutive_timeouts': max_consecutive_timeouts,
+        'not_run': False,
+        'loader_metrics': loader_metrics,
+        'node_metrics': node_metrics,
+        'host_metrics': None,
         'deep_raw_output': deep_raw_output,
         'deep_metrics': deep_metrics,
         'deep_rpc_error_pairs': deep_rpc_error_pairs,
+        'deep_method_metrics': deep_method_metrics,
+        'deep_time_series': deep_time_series,
+        'deep_slowest_calls': deep_slowest_calls,
+        'deep_latency_breakdowns': deep_latency_breakdowns,
     }
 
+
+def _count_max_consecutive_timeouts(
+    attack_output: bytes, engine: spec.LoadEngine
+) -> int:
+    """count longest run of timed out requests, ordered by send time"""
+    if engine == 'native':
+        results = [
+            (result['timestamp'], result['error'])
+            for result in native.decode_native_results(attack_output)
+        ]
+    else:
+        import csv
+        import io
+        import subprocess
+
+        cmd = 'vegeta encode --to csv'
+        output = subprocess.check_output(cmd.split(' '), input=attack_output)
+        results = [
+            (int(row[0]), row[5])
+            for row in csv.reader(io.StringIO(output.decode()))
+            if len(row) > 5
+        ]
+
+    longest = 0
+    current = 0
+    for timestamp, error in sorted(results, key=lambda result: result[0]):
+        if _is_timeout_error(error):
+            current += 1
+            longest = max(longest, current)
+        else:
+            current = 0
+    return longest
+
+
+def _is_timeout_error(error: str | None) -> bool:
+    if error is None:
+        return False
+    error = error.lower()
+    return 'timeout' in error or 'deadline exceeded' in error
+
+
+def _create_vegeta_histogram(
+    attack_output: bytes,
+    min_latency: float | None,
+    max_latency: float,
+) -> spec.LatencyHistogram:
+    """bucket latencies using `vegeta report -type hist[...]`
+
+    only buckets between min_latency and max_latency are passed to vegeta
+    """
+    import subprocess
+    import numpy as np
+
+    sub_buckets = latency_histograms.default_histogram_sub_buckets
+    histogram_min_latency = latency_histograms.default_histogram_min_latency
+    if min_latency is None:
+        min_latency = 0
+    lowest, highest = latency_histograms.get_latency_bucket_indices(
+        np.array([min_latency, max_latency]),
+        sub_buckets=sub_buckets,
+        min_latency=histogram_min_latency,
+    ).tolist()
+    first = lowest
+
+    # boundary k is the lower bound of bucket first + k, in nanoseconds
+    boundaries = [
+        latency_histograms.get_latency_bucket_bounds(
+            bucket,
+            sub_buckets=sub_buckets,
+            min_latency=histogram_min_latency,
+        )[0]
+        for bucket in range(first, highest + 2)
+    ]
+    cmd = 'vegeta report -type=hist['
+    cmd += ','.join(str(int(boundary * 1e9)) + 'ns' for boundary in boundaries)
+    cmd += ']'
+    output = subprocess.check_output(cmd.split(' '), input=attack_output)
+
+    # row k is bucket first + k, the last row is open-ended
+    counts: dict[int, int] = {}
+    rows = [line for line in output.decode().splitlines() if ']' in line]
+    for row, line in enumerate(rows):
+        count = int(line.split(']', 1)[1].split()[0])
+        if count > 0:
+            bucket = first + row
+            counts[bucket] = counts.get(bucket, 0) + count
+    buckets = sorted(counts.keys())
+    return {
+        'sub_buckets': sub_buckets,
+        'min_latency': histogram_min_latency,
+        'buckets': buckets,
+        'counts': [counts[bucket] for bucket in buckets],
+    }
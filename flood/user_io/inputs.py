@@ -24,8 +24,12 @@ def parse_nodes(
     *,
     verbose: bool | int = False,
     request_metadata: bool = False,
+    metrics_urls: typing.Mapping[str, str] | None = None,
 ) -> typing.Mapping[str, spec.Node]:
-    """parse given nodes according to input specification"""
+    """parse given nodes according to input specification
+
+    metrics_urls are prometheus endpoints of nodes, given by node name
+    """
     if verbose:
         outputs.print_header('Gathering node data...')
 
@@ -42,6 +46,16 @@ def parse_nodes(
     else:
         raise Exception('invalid format for nodes')
 
+    if metrics_urls is not None:
+        for name, metrics_url in metrics_urls.items():
+            if name not in new_nodes:
+                raise Exception('metrics url given for unknown node: ' + name)
+            if not metrics_url.startswith(('http://', 'https://')):
+                metrics_url = 'http://' + metrics_url
+            new_nodes[name] = dict(  # type: ignore
+                new_nodes[name], metrics_url=metrics_url
+            )
+
     if verbose:
         print_nodes_table(new_nodes)
 
@@ -54,13 +68,19 @@ def print_nodes_table(nodes: typing.Mapping[str, spec.Node]) -> None:
         url = node['url']
         if node['remote'] is not None:
             url = node['remote'] + '\n' + url
-        client_version = node['client_version']
-        if client_version is not None:
-            client_version = client_version.replace('/', '\n')
+        metadata = node['client_version']
+        if metadata is not None:
+            metadata = metadata.replace('/', '\n')
+        if node.get('network') is not None:
+            network_str = 'network: ' + str(node['network'])
+            if metadata is None:
+                metadata = network_str
+            else:
+                metadata += '\n' + network_str
         row = [
             node['name'],
             url,
-            client_version,
+            metadata,
         ]
         rows.append(row)
     labels = ['node', 'url', 'metadata']
@@ -137,8 +157,16 @@ def parse_node(
             # get client version
             client_version = get_node_client_version(url=url, remote=remote)
 
-            # get network
-            network = 'ethereum'
+            # get network, nodes that cannot be reached are assumed ethereum,
+            # unsupported networks are kept as their chain id
+            chain_id = get_node_chain_id(url=url, remote=remote)
+            network: str | int | None
+            if chain_id is None:
+                network = 'ethereum'
+            else:
+                network = flood.generators.get_network_from_chain_id(chain_id)
+                if network is None:
+                    network = chain_id
 
         else:
             client_version = None
@@ -150,6 +178,7 @@ def parse_node(
             'remote': remote,
             'client_version': client_version,
             'network': network,
+            'metrics_url': None,
         }
 
     else:
@@ -183,21 +212,50 @@ def get_node_client_version(url: str, remote: str | None = None) -> str | None:
         return None
 
 
+def get_node_chain_id(url: str, remote: str | None = None) -> int | None:
+    try:
+        if remote is None:
+            import ctc.rpc
+
+            chain_id: int = ctc.rpc.sync_eth_chain_id(
+                context={'provider': url}
+            )
+            return chain_id
+        else:
+            import json
+            import subprocess
+
+            cmd = [
+                """ssh""",
+                remote,
+                """curl -X POST -H 'Content-Type: application/json' -d '{\"jsonrpc\": \"2.0\", \"method\": \"eth_chainId\", \"params\": [], \"id\": 1}' """  # noqa: E501
+                + url,
+            ]
+            output = subprocess.check_output(cmd, stderr=subprocess.DEVNULL)
+            response = json.loads(output)
+            return int(response['result'], 16)
+    except Exception:
+        return None
+
+
 def parse_test_data(test: spec.LoadTest) -> spec.LoadTestColumnWise:
     rates = []
     durations = []
     vegeta_args = []
     calls = []
+    concurrencies = []
     for attack in test['attacks']:
         rates.append(attack['rate'])
         durations.append(attack['duration'])
         vegeta_args.append(attack['vegeta_args'])
         calls.append(attack['calls'])
+        concurrencies.append(attack.get('concurrency'))
     return {
         'rates': rates,
         'durations': durations,
         'vegeta_args': vegeta_args,
         'calls': calls,
+        'concurrencies': concurrencies,
     }
 
 
@@ -207,10 +265,12 @@ def parse_nodes_network(nodes: typing.Mapping[str, spec.Node]) -> str:
     networks = [node['network'] for node in nodes.values()]
     if len(set(networks)) != 1:
         raise Exception('multiple networks')
-    if networks[0] is None:
-        raise Exception('network could not be determined')
     network = networks[0]
-    if isinstance(network, str):
-        return network
+    if network is None:
+        raise Exception('network could not be determined')
+    elif isinstance(network, int):
+        raise Exception(
+            flood.generators.get_unsupported_chain_id_message(network)
+        )
     else:
-        raise Exception('need str network')
+        return network
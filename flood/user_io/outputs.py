@@ -126,17 +126,43 @@ def print_metric_tables(
         comparison = len(results) == 2
 
     names = list(results.keys())
-    rates = results[names[0]]['target_rate']
+
+    # results tested at different loads are printed as separate tables
+    load_label, rates = _get_result_loads(results[names[0]])
+    if any(
+        _get_result_loads(result) != (load_label, rates)
+        for result in results.values()
+    ):
+        for n, (name, result) in enumerate(results.items()):
+            if n > 0:
+                print()
+            print_metric_tables(
+                results={name: result},
+                metrics=metrics,
+                suffix=suffix + ', ' + name,
+                decimals=decimals,
+                comparison=False,
+                indent=indent,
+            )
+        return
+
     for metric in metrics:
         # create labels
-        if metric in ['success', 'n_invalid_json_errors', 'n_rpc_errors']:
+        if metric in [
+            'success',
+            'n_invalid_json_errors',
+            'n_rpc_errors',
+            'n_schema_errors',
+            'calls',
+            'n_call_errors',
+        ]:
             metric_suffix = ''
-        elif metric == 'throughput':
+        elif metric in ['throughput', 'call_throughput']:
             metric_suffix = ' (rps)'
         else:
             metric_suffix = ' (s)'
         unitted_names = [name + metric_suffix for name in names]
-        labels = ['rate (rps)'] + unitted_names
+        labels = [load_label] + unitted_names
         if comparison:
             if len(results) != 2:
                 raise NotImplementedError('comparison of >2 tests')
@@ -154,7 +180,10 @@ def print_metric_tables(
                 values.append(value)
         if comparison:
             for row in rows:
-                row.append(row[-2] / row[-1])
+                if row[-2] is None or row[-1] in [None, 0]:
+                    row.append(None)
+                else:
+                    row.append(row[-2] / row[-1])
 
         # compute column formats
         if all(value > 1 for value in values if value is not None):
@@ -201,6 +230,119 @@ def print_metric_tables(
             print()
 
 
+def print_error_count_tables(
+    results: typing.Mapping[str, spec.LoadTestDeepOutput],
+    *,
+    max_errors: int = 10,
+    indent: int | str | None = None,
+) -> None:
+    """print most common errors of each result at each load"""
+    import toolstr
+
+    printed = False
+    for name, result in results.items():
+        error_counts = result.get('error_counts')
+        if error_counts is None or not any(error_counts):
+            continue
+        load_label, loads = _get_result_loads(result)
+        rows = []
+        for load, counts in zip(loads, error_counts):
+            for error, count in list(counts.items())[:max_errors]:
+                rows.append([load, count, error])
+
+        if printed:
+            print()
+        toolstr.print_text_box(
+            toolstr.add_style('errors vs load, ' + name, styles.get('metavar')),
+            style=styles.get('content'),
+            indent=indent,
+        )
+        toolstr.print_table(
+            rows,
+            labels=[load_label, 'count', 'error'],
+            label_style=styles.get('metavar'),
+            border=styles.get('content'),
+            indent=indent,
+        )
+        printed = True
+
+
+def print_slowest_calls_tables(
+    results: typing.Mapping[str, spec.LoadTestOutput],
+    *,
+    max_calls: int = 5,
+    max_params_length: int = 60,
+    indent: int | str | None = None,
+) -> None:
+    """print slowest calls of each result at each load with their params"""
+    import json
+    import toolstr
+
+    printed = False
+    for name, result in results.items():
+        slowest_calls = result.get('deep_slowest_calls')
+        if slowest_calls is None or not any(slowest_calls):
+            continue
+        load_label, loads = _get_result_loads(result)
+        rows = []
+        for load, attack_calls in zip(loads, slowest_calls):
+            if attack_calls is None:
+                continue
+            for slow_call in attack_calls[:max_calls]:
+                call = slow_call['call']
+                if isinstance(call, list):
+                    method = 'batch of ' + str(len(call))
+                    params = [item.get('params') for item in call]
+                elif call is not None:
+                    method = call.get('method')
+                    params = call.get('params')
+                else:
+                    method = None
+                    params = None
+                params_str = json.dumps(params)
+                if len(params_str) > max_params_length:
+                    params_str = params_str[: max_params_length - 3] + '...'
+                if slow_call['success']:
+                    status = 'success'
+                else:
+                    status = 'failed (' + str(slow_call['status_code']) + ')'
+                rows.append(
+                    [load, slow_call['latency'], status, method, params_str]
+                )
+
+        if printed:
+            print()
+        toolstr.print_text_box(
+            toolstr.add_style(
+                'slowest calls vs load, ' + name, styles.get('metavar')
+            ),
+            style=styles.get('content'),
+            indent=indent,
+        )
+        toolstr.print_table(
+            rows,
+            labels=[load_label, 'latency (s)', 'status', 'method', 'params'],
+            column_formats={'latency (s)': {'decimals': 6}},
+            label_style=styles.get('metavar'),
+            border=styles.get('content'),
+            indent=indent,
+        )
+        printed = True
+
+
+def _get_result_loads(
+    result: spec.LoadTestOutput | spec.LoadTestDeepOutput,
+) -> tuple[str, typing.Sequence[int | None]]:
+    """get label and values of the load that each sample was tested at"""
+    concurrencies = result.get('target_concurrency')
+    if concurrencies is not None and any(
+        concurrency is not None for concurrency in concurrencies
+    ):
+        return 'concurrency', concurrencies
+    else:
+        return 'rate (rps)', result['target_rate']
+
+
 #
 # # generic restylings of toolstr functions
 #
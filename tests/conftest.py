@@ -44,3 +44,112 @@ def skip_if_env_nodes_unset():
         if os.getenv(var) is None:
             pytest.skip(reason=var + ' env var not set')
 
+
+@pytest.fixture
+def local_rpc_server():
+    """minimal JSON-RPC server on localhost that answers every call"""
+    import http.server
+    import json
+    import threading
+
+    class Handler(http.server.BaseHTTPRequestHandler):
+        def do_POST(self):
+            length = int(self.headers['Content-Length'])
+            request = json.loads(self.rfile.read(length))
+            if isinstance(request, list):
+                response = json.dumps(
+                    [
+                        {'jsonrpc': '2.0', 'id': call['id'], 'result': '0x1'}
+                        for call in request
+                    ]
+                ).encode()
+            else:
+                response = json.dumps(
+                    {'jsonrpc': '2.0', 'id': request['id'], 'result': '0x1'}
+                ).encode()
+            self.send_response(200)
+            self.send_header('Content-Type', 'application/json')
+            self.send_header('Content-Length', str(len(response)))
+            self.end_headers()
+            self.wfile.write(response)
+
+        def log_message(self, *args):
+            pass
+
+    server = http.server.ThreadingHTTPServer(('localhost', 0), Handler)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    yield 'http://localhost:' + str(server.server_address[1])
+    server.shutdown()
+
+
+@pytest.fixture
+def local_metrics_server():
+    """serve a static prometheus metrics file on localhost"""
+    import http.server
+    import threading
+
+    metrics = b"""# HELP process_cpu_seconds_total Total cpu time.
+# TYPE process_cpu_seconds_total counter
+process_cpu_seconds_total 12.5
+# TYPE process_resident_memory_bytes gauge
+process_resident_memory_bytes 1.073741824e+09
+reth_db_read_total{table="Headers"} 1024
+reth_rpc_requests_in_flight 3
+reth_unrelated_gauge 1
+"""
+
+    class Handler(http.server.BaseHTTPRequestHandler):
+        def do_GET(self):
+            self.send_response(200)
+            self.send_header('Content-Type', 'text/plain')
+            self.send_header('Content-Length', str(len(metrics)))
+            self.end_headers()
+            self.wfile.write(metrics)
+
+        def log_message(self, *args):
+            pass
+
+    server = http.server.ThreadingHTTPServer(('localhost', 0), Handler)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    yield 'http://localhost:' + str(server.server_address[1]) + '/metrics'
+    server.shutdown()
+
+
+@pytest.fixture
+def local_ws_rpc_server():
+    """minimal JSON-RPC websocket server on localhost"""
+    import asyncio
+    import json
+    import threading
+
+    from aiohttp import web
+
+    async def handle(request):
+        ws = web.WebSocketResponse()
+        await ws.prepare(request)
+        async for message in ws:
+            call = json.loads(message.data)
+            if isinstance(call, list):
+                response = [
+                    {'jsonrpc': '2.0', 'id': item['id'], 'result': '0x1'}
+                    for item in call
+                ]
+            else:
+                response = {'jsonrpc': '2.0', 'id': call['id'], 'result': '0x1'}
+            await ws.send_str(json.dumps(response))
+        return ws
+
+    loop = asyncio.new_event_loop()
+    app = web.Application()
+    app.router.add_get('/', handle)
+    runner = web.AppRunner(app)
+    loop.run_until_complete(runner.setup())
+    site = web.TCPSite(runner, 'localhost', 0)
+    loop.run_until_complete(site.start())
+    port = site._server.sockets[0].getsockname()[1]
+    thread = threading.Thread(target=loop.run_forever, daemon=True)
+    thread.start()
+    yield 'ws://localhost:' + str(port)
+    loop.call_soon_threadsafe(loop.stop)
@@ -0,0 +1,31 @@
+import polars as pl
+
+import flood
+
+
+def test_generate_block_hashes_from_samples(tmp_path, monkeypatch):
+    hashes = ['0x' + str(i) * 64 for i in range(5)]
+    samples = pl.DataFrame(
+        {
+            'block_number': [100, 101, 102, 103, 104],
+            'block_hash': [bytes.fromhex(h[2:]) for h in hashes],
+            'n_transactions': [10, 0, 25, 3, 7],
+            'gas_used': [1_000_000, 0, 2_500_000, 300_000, 700_000],
+        }
+    )
+    path = tmp_path / 'ethereum_blocks_samples__XS__v1_0_0.parquet'
+    samples.write_parquet(path)
+    monkeypatch.setenv('FLOOD_SAMPLES_DIR', str(tmp_path))
+
+    block_hashes = flood.generators.generate_block_hashes(
+        n=3, network='ethereum', random_seed=0
+    )
+    assert len(block_hashes) == 3
+    assert all(block_hash in hashes for block_hash in block_hashes)
+
+    calls = flood.generators.generate_calls_eth_get_block_by_hash(
+        n_calls=8, network='ethereum', random_seed=0
+    )
+    assert len(calls) == 8
+    assert all(call['method'] == 'eth_getBlockByHash' for call in calls)
+    assert all(call['params'][0] in hashes for call in calls)
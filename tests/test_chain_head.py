@@ -0,0 +1,110 @@
+import flood
+
+
+class MockChainHead:
+    def __init__(self, block_number):
+        self.block_number = block_number
+
+    def get_block_number(self):
+        return self.block_number
+
+
+def test_parse_block_selection():
+    parse = flood.generators.parse_block_selection
+    assert parse('latest') == 'latest'
+    assert parse('finalized') == 'finalized'
+    assert parse('128') == 128
+    for invalid in ['0', 'earliest', 'last']:
+        try:
+            parse(invalid)
+        except Exception:
+            pass
+        else:
+            raise AssertionError('should reject ' + invalid)
+
+
+def test_head_relative_blocks_follow_tip():
+    chain_head = MockChainHead(1000)
+    blocks = flood.generators.HeadRelativeBlocks([0, 5, 127], chain_head)
+    assert blocks[0] == 1000
+
+    # blocks resolved after the tip advances use the new head
+    chain_head.block_number = 1012
+    assert list(blocks) == [1000, 1007, 885]
+    assert list(blocks[1:]) == [1007, 885]
+
+    ranges = flood.generators.HeadRelativeBlockRanges([0, 20], 10, chain_head)
+    assert list(ranges) == [(1002, 1012), (982, 992)]
+    chain_head.block_number = 1020
+    assert list(ranges) == [(1002, 1012), (982, 992)]
+
+
+def test_lazy_calls_follow_chain_head():
+    class PolledChainHead(flood.generators.ChainHead):
+        block_number = 1000
+
+        def _poll_block_number(self):
+            return self.block_number
+
+    chain_head = PolledChainHead(None, refresh_interval=0)
+    blocks = flood.generators.HeadRelativeBlocks([0, 1] * 4, chain_head)
+    calls = flood.generators.LazyCalls(
+        lambda block: {
+            'jsonrpc': '2.0',
+            'method': 'eth_getBlockByNumber',
+            'params': [hex(block), False],
+        },
+        blocks,
+    )
+
+    # calls constructed as a long attack runs follow the advancing tip
+    sent = []
+    for call in calls:
+        sent.append(call)
+        chain_head.block_number += 1
+    assert [int(call['params'][0], 16) for call in sent] == [
+        1000,
+        1000,
+        1002,
+        1002,
+        1004,
+        1004,
+        1006,
+        1006,
+    ]
+
+    # calls constructed again for deep checks match the calls that were sent
+    assert list(calls) == sent
+    assert list(calls[4:]) == sent[4:]
+
+
+def test_chain_head_polls_websocket_nodes(monkeypatch):
+    from flood.generators.object_generators import chain_head
+
+    requests = []
+
+    def request_over_websocket(url, call):
+        requests.append((url, call['method']))
+        return {'jsonrpc': '2.0', 'id': 1, 'result': hex(1000 + len(requests))}
+
+    monkeypatch.setattr(
+        chain_head, '_request_over_websocket', request_over_websocket
+    )
+    head = chain_head.ChainHead('ws://localhost:8546', refresh_interval=0)
+    assert head.get_block_number() == 1001
+    assert head.get_block_number() == 1002
+    assert requests == [('ws://localhost:8546', 'eth_blockNumber')] * 2
+
+
+def test_head_relative_block_tags():
+    block_numbers = flood.generators.generate_head_relative_block_numbers(
+        3, blocks='safe'
+    )
+    assert list(block_numbers) == ['safe', 'safe', 'safe']
+
+    block_numbers = flood.generators.generate_head_relative_block_numbers(
+        100, blocks=16, random_seed=0
+    )
+    assert len(block_numbers) == 100
+    block_numbers.chain_head = MockChainHead(500)
+    assert all(485 <= block <= 500 for block in block_numbers)
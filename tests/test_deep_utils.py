@@ -0,0 +1,204 @@
+import polars as pl
+
+import flood
+
+
+def test_corrected_latency():
+    ms = 1_000_000
+    df = pl.DataFrame(
+        {
+            'timestamp': [0, 100 * ms, 500 * ms, 510 * ms],
+            'latency': [10 * ms] * 4,
+            'index': [0, 1, 2, 3],
+        }
+    )
+    deep_utils = flood.tests.load_tests.deep_utils
+    corrected = deep_utils._add_corrected_latency(
+        df, target_rate=10, target_concurrency=None, offsets=None
+    )
+    assert corrected['corrected_latency'].to_list() == [
+        10 * ms,
+        10 * ms,
+        310 * ms,
+        220 * ms,
+    ]
+
+    # closed-loop attacks have no schedule to correct against
+    corrected = deep_utils._add_corrected_latency(
+        df, target_rate=0, target_concurrency=4, offsets=None
+    )
+    assert corrected['corrected_latency'].null_count() == 4
+
+
+def test_count_response_errors():
+    count = flood.tests.load_tests.deep_utils._count_response_errors
+
+    # legitimate null results are not errors
+    response = {'jsonrpc': '2.0', 'id': 1, 'result': None}
+    assert count(response, 'eth_getTransactionReceipt') == (0, 0)
+    assert count(response, 'eth_getBalance') == (0, 1)
+    assert count(response, 'trace_block') == (1, 0)
+
+    # malformed results are schema errors
+    response = {'jsonrpc': '2.0', 'id': 1, 'result': '0x01'}
+    assert count(response, 'eth_getBalance') == (0, 1)
+    response = {'jsonrpc': '2.0', 'id': 1, 'result': '0x1'}
+    assert count(response, 'eth_getBalance') == (0, 0)
+
+    response = {'jsonrpc': '2.0', 'id': 1, 'error': {'code': -32000}}
+    assert count(response, 'eth_call') == (1, 0)
+
+
+def test_count_batch_errors():
+    deep_utils = flood.tests.load_tests.deep_utils
+    calls = [
+        {'jsonrpc': '2.0', 'method': 'eth_blockNumber', 'params': [], 'id': i}
+        for i in range(3)
+    ]
+    responses = [
+        {'jsonrpc': '2.0', 'id': 0, 'result': '0x10'},
+        {'jsonrpc': '2.0', 'id': 1, 'result': 16},
+    ]
+    assert deep_utils._count_batch_errors(responses, calls) == (1, 1)
+
+    # websocket batches replace ids with '{seq}.{i}'
+    calls[1] = dict(calls[1], method='eth_getBalance')
+    responses = [
+        {'jsonrpc': '2.0', 'id': '7.1', 'result': '0x01'},
+        {'jsonrpc': '2.0', 'id': '7.0', 'result': '0x10'},
+        {'jsonrpc': '2.0', 'id': '7.2', 'result': '0x10'},
+    ]
+    assert deep_utils._count_batch_errors(responses, calls) == (0, 1)
+
+
+def test_rpc_error_keys():
+    deep_utils = flood.tests.load_tests.deep_utils
+    key = deep_utils.get_rpc_error_key(
+        {
+            'code': -32000,
+            'message': 'missing trie node 1a2b3c4d5e6f7a8b9c0d (path )',
+        }
+    )
+    assert key == '-32000 missing trie node 0x... (path )'
+
+    response = [
+        {'jsonrpc': '2.0', 'id': 0, 'error': {'code': -32005, 'message': 'x'}},
+        {'jsonrpc': '2.0', 'id': 1, 'result': None},
+    ]
+    keys = deep_utils._get_response_error_keys(
+        response, n_errors=2, n_schema_errors=0
+    )
+    assert keys == ['-32005 x', 'null or missing result']
+
+
+def test_gather_slowest_calls():
+    import base64
+    import json
+
+    calls = [
+        {'jsonrpc': '2.0', 'method': 'eth_getBalance', 'params': [i], 'id': i}
+        for i in range(3)
+    ]
+    responses = [
+        base64.b64encode(
+            json.dumps({'jsonrpc': '2.0', 'id': i, 'result': '0x1'}).encode()
+        ).decode()
+        for i in range(3)
+    ]
+    df = pl.DataFrame(
+        {
+            'index': [0, 1, 2],
+            'response': responses,
+            'latency': [10_000_000, 30_000_000, 20_000_000],
+            'status_code': [200, 200, 200],
+            'deep_success': [True, True, True],
+            'call_position': [0, 1, 2],
+        }
+    )
+    deep_utils = flood.tests.load_tests.deep_utils
+    slowest = deep_utils._gather_slowest_calls(df, calls, n=2)
+    assert [item['call']['id'] for item in slowest] == [1, 2]
+    assert [item['latency'] for item in slowest] == [0.03, 0.02]
+
+
+def test_get_response_call_position():
+    deep_utils = flood.tests.load_tests.deep_utils
+    calls = flood.generators.LazyCalls(
+        lambda method: {'jsonrpc': '2.0', 'method': method, 'params': []},
+        ['eth_call'] * 10,
+    )[4:8]
+    batches = flood.tests.load_tests.create_call_batches(calls, batch_size=2)
+    listed = [dict(call, id=call['id'] % 2) for call in calls]
+
+    def get_position(decoded, index=0, transport='POST', calls=calls):
+        return deep_utils._get_response_call_position(
+            index=index,
+            decoded=decoded,
+            transport=transport,
+            n_calls=len(calls),
+            get_call_position=deep_utils._get_call_position_lookup(calls),
+        )
+
+    # responses are matched by id, even when received out of order
+    assert get_position({'id': 6, 'result': '0x1'}) == 2
+    assert get_position([{'id': 7}, {'id': 6}], calls=batches) == 1
+
+    # target index is used for missing, unknown, or ambiguous ids
+    assert get_position({'id': None, 'error': {}}, index=5) == 1
+    assert get_position({'id': 0, 'result': '0x1'}, index=3) == 3
+    assert get_position([{'id': 5}, {'id': 6}], index=0, calls=batches) == 0
+    assert get_position({'id': 1}, index=2, calls=listed) == 2
+    assert get_position(None, index=1) == 1
+
+    # websocket ids are replaced by the seq of each request
+    assert get_position({'id': 6}, index=1, transport='WS') == 1
+
+
+def test_compute_deep_datum_pairs_responses_by_id():
+    import json
+
+    native = flood.tests.load_tests.native
+    methods = ['eth_getBalance', 'eth_getLogs', 'eth_call']
+    calls = flood.generators.LazyCalls(
+        lambda method: {'jsonrpc': '2.0', 'method': method, 'params': []},
+        methods,
+    )
+
+    # responses are received in a different order than their calls
+    bodies = [
+        {'jsonrpc': '2.0', 'id': 2, 'result': '0x1'},
+        {'jsonrpc': '2.0', 'id': 0, 'error': {'code': -32000, 'message': 'x'}},
+        {'jsonrpc': '2.0', 'id': 1, 'result': []},
+    ]
+    latencies = [30_000_000, 20_000_000, 10_000_000]
+    raw_output = native._encode_native_results(
+        [
+            {
+                'seq': seq,
+                'code': 200,
+                'timestamp': 1_700_000_000_000_000_000 + seq * 1_000_000,
+                'latency': latency,
+                'bytes_out': 0,
+                'bytes_in': 0,
+                'error': '',
+                'body': json.dumps(body).encode(),
+                'method': 'POST',
+                'url': 'http://localhost:8545',
+            }
+            for seq, (body, latency) in enumerate(zip(bodies, latencies))
+        ]
+    )
+    deep_datum = flood.tests.load_tests.deep_utils.compute_deep_datum(
+        raw_output=raw_output,
+        target_rate=1000,
+        target_duration=1,
+        calls=calls,
+    )
+    _, error_pairs, method_data, _, slowest_calls, _ = deep_datum
+    assert [call['method'] for call, response in error_pairs] == [
+        'eth_getBalance'
+    ]
+    assert slowest_calls[0]['call']['method'] == 'eth_call'
+    assert method_data is not None
+    assert method_data['eth_getBalance']['n_rpc_errors'] == 1
+    assert method_data['eth_call']['n_rpc_errors'] == 0
@@ -0,0 +1,113 @@
+import pytest
+
+import flood
+
+
+calls = [
+    {'jsonrpc': '2.0', 'method': 'eth_blockNumber', 'params': [], 'id': i}
+    for i in range(10)
+]
+
+
+def test_split_attack():
+    attack: flood.VegetaAttack = {
+        'rate': 5,
+        'duration': 2,
+        'calls': calls,
+        'vegeta_args': None,
+        'concurrency': None,
+        'think_time': None,
+        'offsets': None,
+    }
+    shares = [
+        flood.tests.load_tests.split_attack(
+            attack, loader_index=index, n_loaders=2
+        )
+        for index in range(2)
+    ]
+    assert [share['rate'] for share in shares] == [3, 2]
+    assert [call['id'] for call in shares[0]['calls']] == [0, 2, 4, 6, 8]
+    assert [call['id'] for call in shares[1]['calls']] == [1, 3, 5, 7, 9]
+
+
+def test_merge_loader_raw_outputs():
+    native = flood.tests.load_tests.native
+    raw_outputs = []
+    for loader_index in range(2):
+        results = [
+            {
+                'seq': seq,
+                'code': 200,
+                'timestamp': 1_000_000_000 * (2 * seq + loader_index),
+                'latency': 1_000_000 * (loader_index + 1),
+                'bytes_out': 10,
+                'bytes_in': 20,
+                'error': '',
+                'body': b'{"result": "0x1"}',
+                'method': 'POST',
+                'url': 'http://localhost:8545',
+            }
+            for seq in range(3)
+        ]
+        raw_outputs.append(native._encode_native_results(results))
+
+    merged = flood.tests.load_tests.merge_loader_raw_outputs(
+        raw_outputs, n_calls=6
+    )
+    results = native.decode_native_results(merged)
+    assert [result['seq'] for result in results] == [0, 1, 2, 3, 4, 5]
+    assert [result['latency'] for result in results] == [1e6, 2e6] * 3
+    report = native.create_native_report(merged)
+    assert report['requests'] == 6
+    assert report['latencies']['max'] == 2_000_000
+
+    # loaders that send more requests than they have calls cycle through them
+    merged = flood.tests.load_tests.merge_loader_raw_outputs(
+        raw_outputs, n_calls=3
+    )
+    seqs = [result['seq'] for result in native.decode_native_results(merged)]
+    assert seqs == [0, 1, 2, 4, 3, 7]
+    assert [seq % 3 for seq in seqs] == [0, 1, 2, 1, 0, 1]
+
+
+def _run_failing_loader(node, test, **kwargs):
+    import sys
+    import time
+
+    if test['loader_index'] == 0:
+        sys.exit(1)
+    time.sleep(60)
+
+
+def test_distributed_load_test_stops_loaders_on_failure(monkeypatch):
+    import multiprocessing
+    import time
+
+    monkeypatch.setattr(
+        flood.tests.load_tests.load_test_runs,
+        'run_load_test',
+        _run_failing_loader,
+    )
+    monkeypatch.setattr(
+        flood.tests.load_tests.distributed_load_tests,
+        'distributed_start_delay',
+        0,
+    )
+    node: flood.Node = {
+        'name': 'node',
+        'url': 'http://localhost:8545',
+        'remote': None,
+        'client_version': None,
+        'network': None,
+        'metrics_url': None,
+    }
+    test = {'attacks': [], 'test_parameters': {}}
+    t_start = time.time()
+    with pytest.raises(Exception):
+        flood.tests.load_tests.run_distributed_load_test(
+            node=node,
+            test=test,
+            loaders=['loader0', 'loader1'],
+        )
+    assert time.time() - t_start < 30
+    assert multiprocessing.active_children() == []
@@ -0,0 +1,98 @@
+import flood
+
+
+def test_host_metrics_time_series():
+    samples = []
+    for i in range(3):
+        counters = {
+            'cpu_busy': 50.0 * i,
+            'cpu_total': 100.0 * i,
+            'memory': 0.5,
+            'disk_read': 1000.0 * i,
+            'disk_write': 0.0,
+            'network_receive': 2000.0 * i,
+            'network_transmit': 500.0 * i,
+        }
+        samples.append((float(i), counters))
+
+    host_monitoring = flood.tests.load_tests.host_monitoring
+    time_series = host_monitoring._create_host_metrics_time_series(
+        interval=1.0, samples=samples
+    )
+    assert time_series is not None
+    assert time_series['time'] == [0.0, 1.0]
+    assert time_series['cpu'] == [0.5, 0.5]
+    assert time_series['memory'] == [0.5, 0.5]
+    assert time_series['disk_read'] == [1000.0, 1000.0]
+    assert time_series['network_transmit'] == [500.0, 500.0]
+
+
+def test_host_metrics_time_series_too_few_samples():
+    host_monitoring = flood.tests.load_tests.host_monitoring
+    assert (
+        host_monitoring._create_host_metrics_time_series(
+            interval=1.0, samples=[]
+        )
+        is None
+    )
+
+
+def test_parse_host_counters():
+    lines = [
+        '__flood_section__ blocks\n',
+        'loop0  sda\n',
+        '__flood_section__ stat\n',
+        'cpu  100 0 100 700 100 0 0 0 0 0\n',
+        'cpu0 100 0 100 700 100 0 0 0 0 0\n',
+        '__flood_section__ meminfo\n',
+        'MemTotal:       1000 kB\n',
+        'MemAvailable:    250 kB\n',
+        '__flood_section__ diskstats\n',
+        '   8       0 sda 10 0 4 0 20 0 8 0 0 0 0\n',
+        '   8       1 sda1 10 0 4 0 20 0 8 0 0 0 0\n',
+        '   7       0 loop0 10 0 4 0 20 0 8 0 0 0 0\n',
+        '__flood_section__ net/dev\n',
+        'Inter-|   Receive |  Transmit\n',
+        ' face |bytes packets|bytes packets\n',
+        '    lo: 100 1 0 0 0 0 0 0 100 1 0 0 0 0 0 0\n',
+        '  eth0: 300 3 0 0 0 0 0 0 200 2 0 0 0 0 0 0\n',
+    ]
+    host_monitoring = flood.tests.load_tests.host_monitoring
+    counters = host_monitoring.parse_host_counters(lines)
+    assert counters == {
+        'cpu_busy': 200.0,
+        'cpu_total': 1000.0,
+        'memory': 0.75,
+        'disk_read': 4 * 512,
+        'disk_write': 8 * 512,
+        'network_receive': 300,
+        'network_transmit': 200,
+    }
+
+
+def test_split_host_samples():
+    samples = []
+    for i in range(6):
+        counters = {
+            'cpu_busy': 10.0 * i,
+            'cpu_total': 100.0 * i,
+            'memory': 0.5,
+            'disk_read': 0.0,
+            'disk_write': 0.0,
+            'network_receive': 0.0,
+            'network_transmit': 0.0,
+        }
+        samples.append((1000.0 + i, counters))
+
+    host_monitoring = flood.tests.load_tests.host_monitoring
+    time_series = host_monitoring.split_host_samples(
+        samples,
+        interval=1.0,
+        attack_times=[(1000.0, 1002.0), None, (1003.0, 1005.5)],
+    )
+    assert time_series[0] is not None
+    assert time_series[0]['time'] == [0.0, 1.0]
+    assert time_series[1] is None
+    assert time_series[2] is not None
+    assert time_series[2]['time'] == [0.0, 1.0]
+    assert time_series[2]['cpu'] == [0.1, 0.1]
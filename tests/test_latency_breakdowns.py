@@ -0,0 +1,87 @@
+import polars as pl
+import pytest
+
+import flood
+
+
+def test_call_block_parameters():
+    load_tests = flood.tests.load_tests
+    call = {
+        'jsonrpc': '2.0',
+        'method': 'eth_getBalance',
+        'params': ['0x5f98805a4e8be255a32880fdec7f6728c6568ba0', '0x10'],
+        'id': 0,
+    }
+    assert load_tests.get_call_block_number(call) == 16
+    assert load_tests.get_call_block_range(call) is None
+
+    call = {
+        'jsonrpc': '2.0',
+        'method': 'eth_getLogs',
+        'params': [{'fromBlock': '0x10', 'toBlock': '0x1f'}],
+        'id': 1,
+    }
+    assert load_tests.get_call_block_number(call) == 16
+    assert load_tests.get_call_block_range(call) == 16
+
+    call['params'] = [{'fromBlock': 'latest', 'toBlock': 'latest'}]
+    assert load_tests.get_call_block_number(call) is None
+
+
+def test_compute_latency_breakdowns():
+    calls = [
+        {'jsonrpc': '2.0', 'method': 'trace_block', 'params': [block], 'id': i}
+        for i, block in enumerate([100, 100, 199, 199])
+    ]
+    df = pl.DataFrame(
+        {
+            'index': [0, 1, 2, 3],
+            'latency': [10_000_000, 10_000_000, 50_000_000, 50_000_000],
+            'status_code': [200, 200, 200, 500],
+            'bytes_in': [1, 1, 1000, 1000],
+        }
+    )
+    breakdowns = flood.tests.load_tests.compute_latency_breakdowns(
+        df, calls, n_block_number_bins=2
+    )
+    assert set(breakdowns.keys()) == {'block_number', 'response_bytes'}
+    block_number = breakdowns['block_number']
+    assert block_number['bin_lower'] == [100.0, 150.0]
+    assert block_number['requests'] == [2, 2]
+    assert block_number['success'] == [1.0, 0.5]
+    assert block_number['p50'] == [0.01, 0.05]
+
+    breakdowns = flood.tests.load_tests.compute_latency_breakdowns(
+        df, calls, head_block=200
+    )
+    block_age = breakdowns['block_age']
+    assert block_age['bin_lower'][:2] == [0.0, 1.0]
+    assert block_age['requests'][:2] == [0, 2]
+    assert sum(block_age['requests']) == 4
+    assert block_age['p50'][1] == 0.05
+
+
+def test_compute_latency_breakdowns_batches():
+    calls = [
+        {'jsonrpc': '2.0', 'method': 'trace_block', 'params': [block], 'id': i}
+        for i, block in enumerate([100, 199, 199])
+    ]
+    df = pl.DataFrame(
+        {
+            'index': [0, 1],
+            'latency': [10_000_000, 50_000_000],
+            'status_code': [200, 500],
+            'bytes_in': [1, 1000],
+        }
+    )
+    breakdowns = flood.tests.load_tests.compute_latency_breakdowns(
+        df, [calls[:2], calls[2]], n_block_number_bins=2
+    )
+
+    # each call of a batch is counted with the latency of its batch
+    block_number = breakdowns['block_number']
+    assert block_number['requests'] == [1, 2]
+    assert block_number['success'] == [1.0, 0.5]
+    assert block_number['p50'][0] == 0.01
+    assert block_number['p50'][1] == pytest.approx(0.03)
+    assert breakdowns['response_bytes']['requests'][1] == 1
@@ -0,0 +1,81 @@
+import pytest
+
+import flood
+
+
+def test_latency_histogram_quantiles():
+    load_tests = flood.tests.load_tests
+    latencies = [0.001 * i for i in range(1, 1001)]
+    histogram = load_tests.create_latency_histogram(latencies)
+    assert sum(histogram['counts']) == 1000
+    assert histogram['buckets'] == sorted(histogram['buckets'])
+
+    # quantiles are upper bounds within the relative error of one bucket
+    error = 2 ** (1 / histogram['sub_buckets'])
+    for quantile in [0.5, 0.9, 0.99, 0.999]:
+        estimate = load_tests.get_latency_histogram_quantile(
+            histogram, quantile
+        )
+        assert quantile <= estimate <= quantile * error
+
+    latencies, fractions = load_tests.get_latency_histogram_cdf(histogram)
+    assert fractions[-1] == 1.0
+    assert latencies == sorted(latencies)
+
+
+def test_merge_latency_histograms():
+    load_tests = flood.tests.load_tests
+    first = load_tests.create_latency_histogram([0.01, 0.02, 0.02])
+    second = load_tests.create_latency_histogram([0.02, 5.0])
+    merged = load_tests.merge_latency_histograms([first, None, second])
+    assert merged == load_tests.create_latency_histogram(
+        [0.01, 0.02, 0.02, 0.02, 5.0]
+    )
+
+    other = load_tests.create_latency_histogram([0.01], sub_buckets=8)
+    with pytest.raises(Exception):
+        load_tests.merge_latency_histograms([first, other])
+
+
+def test_vegeta_histogram_buckets(monkeypatch):
+    import subprocess
+
+    latencies = [0.0105, 0.012, 0.012, 0.0171, 0.0199]
+
+    def vegeta_report(cmd, input):
+        # format and bucketing of `vegeta report -type=hist[...]`
+        boundaries = [
+            int(boundary[:-2])
+            for boundary in cmd[-1].split('[')[1].rstrip(']').split(',')
+        ]
+        counts = [0] * len(boundaries)
+        for latency in latencies:
+            ns = int(latency * 1e9)
+            i = 0
+            while i < len(boundaries) - 1:
+                if boundaries[i] <= ns < boundaries[i + 1]:
+                    break
+                i += 1
+            counts[i] += 1
+        lines = ['Bucket           #  %       Histogram']
+        for i, count in enumerate(counts):
+            upper = (
+                str(boundaries[i + 1]) + 'ns'
+                if i + 1 < len(boundaries)
+                else '+Inf'
+            )
+            lines.append(
+                '[' + str(boundaries[i]) + 'ns,  ' + upper + ']  '
+                + str(count) + '  '
+                + '%.2f%%' % (100 * count / len(latencies))
+                + '  ' + '#' * count
+            )
+        return ('\n'.join(lines) + '\n').encode()
+
+    monkeypatch.setattr(subprocess, 'check_output', vegeta_report)
+    vegeta = flood.tests.load_tests.vegeta
+    histogram = vegeta._create_vegeta_histogram(
+        b'', min_latency=min(latencies), max_latency=max(latencies)
+    )
+    expected = flood.tests.load_tests.create_latency_histogram(latencies)
+    assert histogram == expected
@@ -0,0 +1,129 @@
+import flood
+
+
+calls = [
+    {'jsonrpc': '2.0', 'method': 'eth_blockNumber', 'params': [], 'id': i}
+    for i in range(10)
+]
+
+
+def test_native_engine(local_rpc_server):
+    result = flood.tests.load_tests.run_vegeta_attack(
+        url=local_rpc_server,
+        rate=10,
+        duration=2,
+        calls=calls,
+        engine='native',
+        include_deep_output=['metrics'],
+    )
+    assert result['requests'] == 20
+    assert result['success'] == 1.0
+    assert result['status_codes'] == {'200': 20}
+    assert result['deep_metrics']['failed']['requests'] == 0
+    assert result['deep_metrics']['successful']['requests'] == 20
+
+
+def test_native_engine_websocket(local_ws_rpc_server):
+    result = flood.tests.load_tests.run_vegeta_attack(
+        url=local_ws_rpc_server,
+        rate=10,
+        duration=2,
+        calls=calls,
+        include_deep_output=['metrics'],
+    )
+    assert result['requests'] == 20
+    assert result['success'] == 1.0
+    assert result['deep_metrics']['successful']['requests'] == 20
+
+
+def test_native_engine_batches(local_rpc_server):
+    batches = flood.tests.load_tests.create_call_batches(calls, batch_size=5)
+    result = flood.tests.load_tests.run_vegeta_attack(
+        url=local_rpc_server,
+        rate=10,
+        duration=1,
+        calls=batches,
+        engine='native',
+        include_deep_output=['metrics'],
+    )
+    deep_all = result['deep_metrics']['all']
+    assert deep_all['requests'] == 10
+    assert deep_all['calls'] == 50
+    assert deep_all['n_call_errors'] == 0
+
+
+def test_native_engine_concurrency(local_rpc_server):
+    result = flood.tests.load_tests.run_vegeta_attack(
+        url=local_rpc_server,
+        rate=0,
+        concurrency=4,
+        think_time=0.1,
+        duration=1,
+        calls=calls,
+        engine='native',
+        include_deep_output=['metrics'],
+    )
+    assert result['target_rate'] is None
+    assert result['target_concurrency'] == 4
+    assert result['success'] == 1.0
+    # each worker sends at most one request per think time
+    assert 4 <= result['requests'] <= 4 * 11
+    assert result['deep_metrics']['all']['target_concurrency'] == 4
+
+
+def test_stop_conditions(local_rpc_server):
+    attacks = flood.tests.load_tests.create_load_test(
+        calls=calls, rates=[2, 4], duration=1, repeat_calls=True
+    )
+    output = flood.tests.load_tests.run_load_test(
+        node=local_rpc_server,
+        test={'attacks': attacks},  # type: ignore
+        engine='native',
+        include_deep_output=['metrics'],
+        stop_conditions={
+            'min_success': None,
+            'max_p99': 0,
+            'max_consecutive_timeouts': None,
+        },
+    )
+    assert output['not_run'] == [False, True]
+    assert output['target_rate'] == [2, 4]
+    assert output['requests'] == [2, 0]
+    assert output['p99'][1] is None
+    assert output['deep_metrics']['all']['requests'] == [2, 0]
+
+
+def test_single_run_start_time(local_rpc_server, tmp_path):
+    import time
+
+    start_time = time.time() + 1
+    output = flood.run(
+        'eth_getBlockByNumber',
+        nodes=[local_rpc_server],
+        rates=[2],
+        duration=1,
+        blocks='latest',
+        engine='native',
+        verbose=False,
+        figures=False,
+        output_dir=str(tmp_path),
+        start_time=start_time,
+    )
+    results = output['single_run']['payload']['results']
+    (result,) = results.values()
+    assert result['requests'] == [2]
+    first_request = flood.tests.load_tests.native._parse_timestamp_ns(
+        result['first_request_timestamp'][0]
+    )
+    assert first_request >= start_time * 1e9
+
+
+def test_native_timestamp_roundtrip():
+    native = flood.tests.load_tests.native
+    timestamp = 1_684_000_000_123_456_789
+    formatted = native._format_timestamp_ns(timestamp)
+    assert formatted == '2023-05-13T17:46:40.123456789Z'
+    assert native._parse_timestamp_ns(formatted) == timestamp
+    assert native._parse_timestamp_ns('2023-05-13T10:46:40.5-07:00') == (
+        1_684_000_000_500_000_000
+    )
@@ -0,0 +1,75 @@
+import pytest
+
+import flood
+
+
+calls = [
+    {'jsonrpc': '2.0', 'method': 'eth_blockNumber', 'params': [], 'id': i}
+    for i in range(100)
+]
+
+
+@pytest.mark.parametrize('batch_size', [None, 1, 3, 10])
+def test_create_load_test_batch_size(batch_size):
+    rates = [2, 3]
+    n_calls = flood.tests.load_tests.estimate_call_count(
+        rates=rates, duration=2, batch_size=batch_size
+    )
+    attacks = flood.tests.load_tests.create_load_test(
+        calls=calls[:n_calls],
+        rates=rates,
+        duration=2,
+        batch_size=batch_size,
+    )
+    for attack in attacks:
+        assert len(attack['calls']) == attack['rate'] * attack['duration']
+        if batch_size is not None:
+            assert all(len(batch) == batch_size for batch in attack['calls'])
+
+
+def test_create_call_batches():
+    batches = flood.tests.load_tests.create_call_batches(calls[:7], 3)
+    assert [len(batch) for batch in batches] == [3, 3, 1]
+    assert [call for batch in batches for call in batch] == calls[:7]
+
+
+def test_create_load_test_concurrency():
+    concurrencies, durations = flood.generators.generate_timings(
+        rates=[1, 2], duration=2, mode='concurrency'
+    )
+    n_calls = flood.tests.load_tests.estimate_call_count(
+        rates=concurrencies, durations=durations, mode='concurrency'
+    )
+    attacks = flood.tests.load_tests.create_load_test(
+        calls=calls[:n_calls],
+        rates=concurrencies,
+        durations=durations,
+        mode='concurrency',
+        think_time=0.5,
+    )
+    assert [attack['concurrency'] for attack in attacks] == [1, 2]
+    assert all(attack['rate'] == 0 for attack in attacks)
+    assert all(attack['think_time'] == 0.5 for attack in attacks)
+
+
+def test_create_load_test_lazy_calls():
+    def construct(block_number):
+        return {
+            'jsonrpc': '2.0',
+            'method': 'eth_getBlockByNumber',
+            'params': [hex(block_number), False],
+        }
+
+    lazy_calls = flood.generators.LazyCalls(construct, block_number=range(30))
+    attacks = flood.tests.load_tests.create_load_test(
+        calls=lazy_calls,
+        rates=[2, 3],
+        duration=2,
+        batch_size=3,
+    )
+    first, second = [attack['calls'] for attack in attacks]
+    assert len(first) == 4 and len(second) == 6
+    assert [call['id'] for call in first[0]] == [0, 1, 2]
+    assert [call['id'] for call in second[0]] == [12, 13, 14]
+    assert second[0][0]['params'] == ['0xc', False]
+    assert second[-1] == [lazy_calls[27], lazy_calls[28], lazy_calls[29]]
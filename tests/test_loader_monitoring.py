@@ -0,0 +1,48 @@
+import time
+
+import flood
+
+
+def test_loader_monitor():
+    monitor = flood.tests.load_tests.LoaderMonitor(interval=0.01)
+    monitor.start()
+    time.sleep(0.1)
+    metrics = monitor.stop()
+    assert metrics['n_samples'] > 0
+    assert metrics['scheduling_lag_max'] >= 0
+
+
+def test_loader_saturation_reasons():
+    metrics: flood.LoaderMetrics = {
+        'interval': 0.25,
+        'n_samples': 4,
+        'cpu_mean': 0.97,
+        'cpu_max': 1.0,
+        'memory_max': 0.5,
+        'sockets_max': 100,
+        'scheduling_lag_mean': 0.001,
+        'scheduling_lag_max': 0.002,
+    }
+    reasons = flood.tests.load_tests.get_loader_saturation_reasons(metrics)
+    assert reasons == ['cpu 97%']
+    metrics['cpu_mean'] = 0.5
+    assert flood.tests.load_tests.get_loader_saturation_reasons(metrics) == []
+
+
+def test_check_loader_utilization(capsys):
+    import os
+
+    load_test_runs = flood.tests.load_tests.load_test_runs
+    n_cpus = os.cpu_count()
+    assert n_cpus is not None
+
+    utilization = load_test_runs._check_loader_utilization(
+        cpu_time=0.95 * n_cpus * 10, wall_time=10, verbose=True
+    )
+    assert utilization is not None and abs(utilization - 0.95) < 1e-9
+    assert 'WARNING' in capsys.readouterr().out
+
+    load_test_runs._check_loader_utilization(
+        cpu_time=0.5 * n_cpus * 10, wall_time=10, verbose=True
+    )
+    assert 'WARNING' not in capsys.readouterr().out
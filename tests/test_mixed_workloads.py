@@ -0,0 +1,102 @@
+import pytest
+
+import flood
+
+
+def test_get_call_generator():
+    get_call_generator = flood.generators.get_call_generator
+    assert (
+        get_call_generator('eth_getLogs')
+        is flood.generators.generate_calls_eth_get_logs
+    )
+    assert (
+        get_call_generator('eth_getBalance')
+        is flood.generators.generate_calls_eth_get_eth_balance
+    )
+    for method in flood.generators.default_mixed_weights.keys():
+        get_call_generator(method)
+
+
+def test_get_call_rpc_methods():
+    calls = [
+        {'jsonrpc': '2.0', 'method': 'eth_call', 'params': [], 'id': 1},
+        {'jsonrpc': '2.0', 'method': 'eth_getLogs', 'params': [], 'id': 2},
+        {'jsonrpc': '2.0', 'method': 'eth_call', 'params': [], 'id': 3},
+    ]
+    get_call_rpc_methods = flood.tests.load_tests.get_call_rpc_methods
+    assert get_call_rpc_methods(calls) == ['eth_call', 'eth_getLogs']
+    assert get_call_rpc_methods([calls[:2], [calls[2]]]) == [
+        'batch',
+        'eth_call',
+    ]
+
+
+def _count_mixed_methods(monkeypatch, weights, n_calls):
+    import collections
+
+    def get_call_generator(method):
+        def generate_calls(n_calls, *, network, random_seed):
+            return [
+                {'jsonrpc': '2.0', 'method': method, 'params': [], 'id': i}
+                for i in range(n_calls)
+            ]
+
+        return generate_calls
+
+    monkeypatch.setattr(
+        flood.generators.object_generators.call_generators,
+        'get_call_generator',
+        get_call_generator,
+    )
+    calls = flood.generators.generate_calls_mixed(
+        n_calls=n_calls,
+        weights=weights,
+        network='ethereum',
+        random_seed=0,
+    )
+    assert len(calls) == n_calls
+    return collections.Counter(call['method'] for call in calls)
+
+
+def test_generate_calls_mixed_weights(monkeypatch):
+    weights = {
+        'eth_call': 40,
+        'eth_getLogs': 30,
+        'eth_getBalance': 20,
+        'trace_block': 10,
+    }
+    counts = _count_mixed_methods(monkeypatch, weights, 1000)
+    assert counts == {
+        'eth_call': 400,
+        'eth_getLogs': 300,
+        'eth_getBalance': 200,
+        'trace_block': 100,
+    }
+
+    # weights do not need to be normalized
+    unnormalized = {method: weight / 7 for method, weight in weights.items()}
+    assert _count_mixed_methods(monkeypatch, unnormalized, 1000) == counts
+
+    # remainders are allocated so that counts sum to n_calls
+    counts = _count_mixed_methods(
+        monkeypatch, {'eth_call': 1, 'eth_getLogs': 1, 'trace_block': 1}, 10
+    )
+    assert sorted(counts.values()) == [3, 3, 4]
+
+    # methods with zero weight get no calls
+    counts = _count_mixed_methods(
+        monkeypatch, {'eth_call': 3, 'eth_getLogs': 0}, 100
+    )
+    assert counts == {'eth_call': 100}
+
+    # a single method gets every call
+    counts = _count_mixed_methods(monkeypatch, {'eth_getLogs': 5}, 100)
+    assert counts == {'eth_getLogs': 100}
+
+
+def test_generate_calls_mixed_invalid_weights():
+    for weights in [{}, {'eth_call': 0, 'eth_getLogs': 0}, {'eth_call': -1}]:
+        with pytest.raises(Exception):
+            flood.generators.generate_calls_mixed(
+                n_calls=10, weights=weights, network='ethereum'
+            )
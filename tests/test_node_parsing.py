@@ -66,3 +66,44 @@ def test_parse_node(
 
     assert parsed['remote'] == remote_client
 
+
+@pytest.mark.parametrize(
+    'chain_id,network',
+    [
+        (1, 'ethereum'),
+        (10, 'optimism'),
+        (8453, 'base'),
+        (None, 'ethereum'),
+        (137, 137),
+    ],
+)
+def test_parse_node_network(monkeypatch, chain_id, network):
+    inputs = flood.user_io.inputs
+    monkeypatch.setattr(
+        inputs, 'get_node_client_version', lambda url, remote: None
+    )
+    monkeypatch.setattr(
+        inputs, 'get_node_chain_id', lambda url, remote: chain_id
+    )
+
+    parsed = flood.user_io.parse_node('node=localhost:8545')
+    assert parsed['network'] == network
+
+    if isinstance(network, int):
+        with pytest.raises(Exception, match='unsupported chain id: 137'):
+            flood.user_io.parse_nodes_network({'node': parsed})
+    else:
+        assert flood.user_io.parse_nodes_network({'node': parsed}) == network
+
+
+def test_network_defaults():
+    assert flood.generators.get_network_from_chain_id(42161) == 'arbitrum'
+    assert flood.generators.get_network_from_chain_id(137) is None
+    for network in ['ethereum', 'optimism', 'base', 'arbitrum', 'sepolia']:
+        start_block, end_block = flood.generators.get_network_block_range(
+            network, 'state'
+        )
+        assert 0 <= start_block < end_block
+        assert 'USDC' in flood.generators.get_network_contracts(network)
+    with pytest.raises(Exception):
+        flood.generators.get_network_defaults('polygon')
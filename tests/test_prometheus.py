@@ -0,0 +1,31 @@
+import time
+
+import flood
+
+
+def test_parse_prometheus_metrics():
+    metrics = flood.tests.load_tests.parse_prometheus_metrics(
+        '# TYPE rpc_calls counter\n'
+        'rpc_calls{method="eth_call",kind="a b"} 7 1684000000000\n'
+        'up 1\n'
+        'bad_value abc\n'
+    )
+    assert metrics == {'rpc_calls{method="eth_call",kind="a b"}': 7, 'up': 1}
+
+
+def test_prometheus_scraper(local_metrics_server):
+    scraper = flood.tests.load_tests.PrometheusScraper(
+        local_metrics_server, interval=0.05
+    )
+    scraper.start()
+    time.sleep(0.2)
+    node_metrics = scraper.stop()
+    assert len(node_metrics['time']) >= 2
+    assert node_metrics['categories'] == {
+        'cpu': ['process_cpu_seconds_total'],
+        'memory': ['process_resident_memory_bytes'],
+        'db_reads': ['reth_db_read_total{table="Headers"}'],
+        'rpc_queue': ['reth_rpc_requests_in_flight'],
+    }
+    memory = node_metrics['series']['process_resident_memory_bytes']
+    assert set(memory) == {1073741824.0}
@@ -0,0 +1,60 @@
+import flood
+
+
+def test_raw_outputs_roundtrip(tmp_path):
+    load_tests = flood.tests.load_tests
+    results = [
+        {
+            'seq': seq,
+            'code': 200,
+            'timestamp': 1_000_000_000 * seq,
+            'latency': 1_000_000 * (seq + 1),
+            'bytes_out': 10,
+            'bytes_in': 20,
+            'error': '',
+            'body': b'{"result": "0x1"}',
+            'method': 'POST',
+            'url': 'http://localhost:8545',
+        }
+        for seq in range(3)
+    ]
+    results[2]['body'] = b'\xff\xfe not utf-8'
+    calls = [
+        {'jsonrpc': '2.0', 'method': 'eth_call', 'params': [], 'id': 0},
+        [
+            {'jsonrpc': '2.0', 'method': 'eth_call', 'params': [], 'id': 1},
+            {'jsonrpc': '2.0', 'method': 'eth_getLogs', 'params': [], 'id': 2},
+        ],
+        {'jsonrpc': '2.0', 'method': 'eth_getBalance', 'params': [], 'id': 3},
+    ]
+    encoded = load_tests.encode_raw_vegeta_output(
+        load_tests.annotate_raw_output(
+            load_tests.native._encode_native_results(results), calls
+        )
+    )
+
+    path = str(tmp_path / 'raw_outputs' / 'node.parquet')
+    load_tests.save_raw_outputs([None, encoded], path)
+    df = load_tests.load_raw_outputs(path)
+    assert df['attack_index'].to_list() == [1, 1, 1]
+    assert df['seq'].to_list() == [0, 1, 2]
+    assert df['method'].to_list() == ['eth_call', 'batch', 'eth_getBalance']
+    assert df['transport'].to_list() == ['POST'] * 3
+    assert df['response'].to_list() == [
+        b'{"result": "0x1"}',
+        b'{"result": "0x1"}',
+        b'\xff\xfe not utf-8',
+    ]
+
+    raw_outputs = load_tests.convert_dataframe_to_raw_outputs(df, n_attacks=2)
+    assert raw_outputs[0] is None
+    decoded = load_tests.native.decode_native_results(
+        load_tests.decode_raw_vegeta_output(raw_outputs[1])  # type: ignore
+    )
+    assert [result['latency'] for result in decoded] == [1e6, 2e6, 3e6]
+
+    # raw outputs converted back from parquet keep their bytes and methods
+    roundtrip_path = str(tmp_path / 'raw_outputs' / 'roundtrip.parquet')
+    load_tests.save_raw_outputs(raw_outputs, roundtrip_path)
+    roundtrip = load_tests.load_raw_outputs(roundtrip_path)
+    assert roundtrip.to_dicts() == df.to_dicts()
@@ -0,0 +1,30 @@
+import json
+
+import flood
+
+
+def test_load_traffic_log(tmp_path):
+    path = tmp_path / 'traffic.jsonl'
+    entries = [
+        {'method': 'eth_chainId', 'params': [], 'timestamp': 1700000002.5},
+        {'method': 'eth_blockNumber', 'timestamp': '2023-11-14T22:13:20Z'},
+        {'method': 'eth_getBalance', 'params': ['0x0'], 'timestamp': 1700000004},
+    ]
+    with open(path, 'w') as f:
+        for entry in entries:
+            f.write(json.dumps(entry) + '\n')
+
+    calls, offsets = flood.generators.load_traffic_log(str(path))
+    assert [call['method'] for call in calls] == [
+        'eth_blockNumber',
+        'eth_chainId',
+        'eth_getBalance',
+    ]
+    assert [call['id'] for call in calls] == [0, 1, 2]
+    assert calls[0]['params'] == []
+    assert offsets == [0.0, 2.5, 4.0]
+
+    attack = flood.generators.generate_replay_attack(str(path), speed=2)
+    assert attack['offsets'] == [0.0, 1.25, 2.0]
+    assert attack['duration'] == 2
+    assert len(attack['calls']) == 3
@@ -0,0 +1,57 @@
+import flood
+
+
+def simulate_search(capacity, start_rate=16, max_rate=65536, precision=0.05):
+    search = flood.tests.load_tests.throughput_search
+    tested_rates = []
+    highest_passing = None
+    lowest_failing = None
+    rate = start_rate
+    while rate is not None:
+        tested_rates.append(rate)
+        if rate <= capacity:
+            highest_passing = rate
+        else:
+            lowest_failing = rate
+        rate = search._get_next_search_rate(
+            rate=rate,
+            highest_passing=highest_passing,
+            lowest_failing=lowest_failing,
+            max_rate=max_rate,
+            precision=precision,
+        )
+    return highest_passing, tested_rates
+
+
+def test_throughput_search_rates():
+    highest_passing, tested_rates = simulate_search(capacity=1000)
+    assert tested_rates[:7] == [16, 32, 64, 128, 256, 512, 1024]
+    assert 1000 * 0.95 <= highest_passing <= 1000
+
+    highest_passing, tested_rates = simulate_search(capacity=5)
+    assert highest_passing == 5
+    assert tested_rates == [16, 8, 4, 6, 5]
+
+    highest_passing, tested_rates = simulate_search(capacity=0)
+    assert highest_passing is None
+
+    highest_passing, tested_rates = simulate_search(
+        capacity=10**9, max_rate=100
+    )
+    assert highest_passing == 100
+    assert tested_rates == [16, 32, 64, 100]
+
+
+def test_meets_throughput_slo():
+    slo = {'max_p99': 0.5, 'min_success': 0.999}
+    result = {
+        'target_rate': 100,
+        'actual_rate': 99.9,
+        'success': 1.0,
+        'p99': 0.1,
+    }
+    meets_throughput_slo = flood.tests.load_tests.meets_throughput_slo
+    assert meets_throughput_slo(result, slo)
+    assert not meets_throughput_slo(dict(result, p99=0.6), slo)
+    assert not meets_throughput_slo(dict(result, success=0.99), slo)
+    assert not meets_throughput_slo(dict(result, actual_rate=50.0), slo)
@@ -0,0 +1,20 @@
+import polars as pl
+
+import flood
+
+
+def test_compute_time_series():
+    df = pl.DataFrame(
+        {
+            'timestamp': [0, 200_000_000, 1_100_000_000, 3_000_000_000],
+            'latency': [100_000_000, 900_000_000, 300_000_000, 50_000_000],
+            'status_code': [200, 200, 500, 200],
+        }
+    )
+    time_series = flood.tests.load_tests.deep_utils.compute_time_series(df)
+    assert time_series['time'] == [0.0, 1.0, 2.0, 3.0]
+    assert time_series['requests'] == [2, 1, 0, 1]
+    assert time_series['success'] == [1.0, 0.0, None, 1.0]
+    assert time_series['throughput'] == [1.0, 1.0, 0.0, 1.0]
+    assert time_series['max'] == [0.9, 0.3, None, 0.05]
+    assert time_series['p50'][2] is None